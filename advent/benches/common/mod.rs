@@ -0,0 +1,102 @@
+// Synthetic workloads shared by the benchmarks. Everything comes from a fixed seed, so every run
+// (and every baseline) measures exactly the same inputs.
+#![allow(dead_code)]
+
+use advent::{Grid, Interval, Point2D};
+
+// SplitMix64: small, fast, and good enough to make up puzzle-like inputs
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // A number from 0 up to but not including n
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+// A maze like the puzzles' ones: corridors one cell wide between # walls, from the top left
+// corner to the bottom right. It's carved as a perfect maze with a depth-first walk, then some
+// walls are knocked out so there are loops and more than one way through. The side is made odd
+// so the maze has a wall all round it.
+pub struct Maze {
+    pub grid: Grid<char>,
+    pub start: Point2D<i32>,
+    pub goal: Point2D<i32>,
+}
+
+impl Maze {
+    pub fn random(side: i32, seed: u64) -> Maze {
+        let side = side.max(5) | 1;
+        let mut rng = Rng::new(seed);
+        let mut grid = Grid::new(side, side, '#');
+        let start = Point2D::new(1, 1);
+        grid[start] = '.';
+        let mut stack = vec![start];
+        while let Some(&p) = stack.last() {
+            let unvisited: Vec<Point2D<i32>> = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+                .into_iter()
+                .map(|(dx, dy)| Point2D::new(p.x + dx, p.y + dy))
+                .filter(|&next| next.x > 0 && next.y > 0 && next.x < side - 1 && next.y < side - 1 && grid[next] == '#')
+                .collect();
+            if unvisited.is_empty() {
+                stack.pop();
+                continue;
+            }
+            let next = unvisited[rng.below(unvisited.len() as u64) as usize];
+            grid[Point2D::new((p.x + next.x) / 2, (p.y + next.y) / 2)] = '.';
+            grid[next] = '.';
+            stack.push(next);
+        }
+        // knock out one wall in ten between two corridors
+        for y in 1..side - 1 {
+            for x in 1..side - 1 {
+                let p = Point2D::new(x, y);
+                let between = (x % 2 == 0) != (y % 2 == 0);
+                if between && grid[p] == '#' && rng.below(10) == 0 {
+                    grid[p] = '.';
+                }
+            }
+        }
+        Maze { grid, start, goal: Point2D::new(side - 2, side - 2) }
+    }
+
+    // The open cells next to p
+    pub fn neighbors(&self, p: &Point2D<i32>) -> Vec<Point2D<i32>> {
+        self.grid.neighbors4(*p).filter(|&next| self.grid[next] != '#').collect()
+    }
+
+    pub fn cells(&self) -> usize {
+        (self.grid.width() * self.grid.height()) as usize
+    }
+}
+
+// n intervals scattered over 0..10n, each up to 20 long, so plenty of them overlap
+pub fn random_intervals(n: usize, seed: u64) -> Vec<Interval<i64>> {
+    let mut rng = Rng::new(seed);
+    let range = 10 * n as u64;
+    (0..n)
+        .map(|_| {
+            let start = rng.below(range) as i64;
+            Interval::new(start, start + 1 + rng.below(20) as i64)
+        })
+        .collect()
+}
+
+// n points scattered over a square about twice as many cells as there are points
+pub fn random_points(n: usize, seed: u64) -> Vec<Point2D<i32>> {
+    let mut rng = Rng::new(seed);
+    let side = ((2 * n) as f64).sqrt().ceil() as u64;
+    (0..n).map(|_| Point2D::new(rng.below(side) as i32, rng.below(side) as i32)).collect()
+}
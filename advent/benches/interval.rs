@@ -0,0 +1,42 @@
+// Building and combining large interval sets, and looking values up in them
+mod common;
+
+use std::hint::black_box;
+
+use advent::{IntervalSet, Interval};
+use common::{random_intervals, Rng};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+fn bench_interval_set(c: &mut Criterion) {
+    let mut group = c.benchmark_group("interval_set");
+    for n in SIZES {
+        let intervals = random_intervals(n, 1);
+        let set: IntervalSet<i64> = intervals.iter().copied().collect();
+        let other: IntervalSet<i64> = random_intervals(n, 2).into_iter().collect();
+        let mut rng = Rng::new(3);
+        let values: Vec<i64> = (0..1000).map(|_| rng.below(10 * n as u64) as i64).collect();
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::new("insert", n), &intervals, |b, intervals| {
+            b.iter(|| black_box(intervals).iter().copied().collect::<IntervalSet<i64>>())
+        });
+        group.bench_with_input(BenchmarkId::new("union", n), &(&set, &other), |b, (set, other)| b.iter(|| black_box(set).union(other)));
+        group.bench_with_input(BenchmarkId::new("intersection", n), &(&set, &other), |b, (set, other)| {
+            b.iter(|| black_box(set).intersection(other))
+        });
+        group.bench_with_input(BenchmarkId::new("difference", n), &(&set, &other), |b, (set, other)| {
+            b.iter(|| black_box(set).difference(other))
+        });
+        group.bench_with_input(BenchmarkId::new("complement", n), &set, |b, set| {
+            b.iter(|| black_box(set).complement(Interval::new(0, 10 * n as i64)))
+        });
+        group.bench_with_input(BenchmarkId::new("contains_value", n), &set, |b, set| {
+            b.iter(|| values.iter().filter(|&&value| black_box(set).contains_value(value)).count())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_interval_set);
+criterion_main!(benches);
@@ -0,0 +1,46 @@
+// Point2D arithmetic and hashing over large point clouds, like the robots of day 14 or the
+// visited sets of the grid days
+mod common;
+
+use std::collections::HashSet;
+use std::hint::black_box;
+
+use advent::Point2D;
+use common::random_points;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+fn bench_points(c: &mut Criterion) {
+    let mut group = c.benchmark_group("point");
+    for n in SIZES {
+        let points = random_points(n, 1);
+        let velocities = random_points(n, 2);
+        let set: HashSet<Point2D<i32>> = points.iter().copied().collect();
+        let space = Point2D::new(101, 103);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::new("hash_set", n), &points, |b, points| {
+            b.iter(|| black_box(points).iter().copied().collect::<HashSet<_>>())
+        });
+        group.bench_with_input(BenchmarkId::new("neighbors", n), &points, |b, points| {
+            b.iter(|| black_box(points).iter().flat_map(|p| p.orthogonal_neighbors()).filter(|p| set.contains(p)).count())
+        });
+        group.bench_with_input(BenchmarkId::new("move_and_wrap", n), &points, |b, points| {
+            b.iter(|| black_box(points).iter().zip(&velocities).map(|(&p, &v)| (p + v * 100).rem_euclid(&space)).collect::<Vec<_>>())
+        });
+        group.bench_with_input(BenchmarkId::new("manhattan_distance", n), &points, |b, points| {
+            b.iter(|| black_box(points).iter().map(|p| p.manhattan_distance(&points[0])).max())
+        });
+        group.bench_with_input(BenchmarkId::new("sort", n), &points, |b, points| {
+            b.iter(|| {
+                let mut sorted = black_box(points).clone();
+                sorted.sort();
+                sorted
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_points);
+criterion_main!(benches);
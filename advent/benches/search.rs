@@ -0,0 +1,43 @@
+// The searches on random mazes of about 10^2 to 10^6 cells, the way days 16, 18 and 20 use them
+mod common;
+
+use std::hint::black_box;
+
+use advent::{all_shortest_paths, bfs, dijkstra_all, shortest_path};
+use common::Maze;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+// Mazes this many cells across, so they have about 10^2, 10^3, ... 10^6 cells
+const SIDES: [i32; 5] = [11, 33, 101, 317, 1001];
+
+fn bench_search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("search");
+    for side in SIDES {
+        let maze = Maze::random(side, 2024);
+        // the biggest mazes take long enough per search that fewer samples still give steady numbers
+        group.sample_size(if side > 300 { 10 } else { 50 });
+        group.throughput(Throughput::Elements(maze.cells() as u64));
+        let cells = maze.cells();
+        group.bench_with_input(BenchmarkId::new("shortest_path/dijkstra", cells), &maze, |b, maze| {
+            b.iter(|| shortest_path(black_box(maze.start), maze.goal, |p| maze.neighbors(p), |_, _| 1, |_, _| 0))
+        });
+        group.bench_with_input(BenchmarkId::new("shortest_path/a_star", cells), &maze, |b, maze| {
+            b.iter(|| {
+                shortest_path(black_box(maze.start), maze.goal, |p| maze.neighbors(p), |_, _| 1, |p, goal| p.manhattan_distance(goal) as u64)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("all_shortest_paths", cells), &maze, |b, maze| {
+            b.iter(|| {
+                all_shortest_paths(black_box(maze.start), maze.goal, |p| maze.neighbors(p), |_, _| 1, |p, goal| p.manhattan_distance(goal) as u64)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("dijkstra_all", cells), &maze, |b, maze| {
+            b.iter(|| dijkstra_all(black_box(maze.start), |p| maze.neighbors(p).into_iter().map(|next| (next, 1u64))))
+        });
+        group.bench_with_input(BenchmarkId::new("bfs", cells), &maze, |b, maze| b.iter(|| bfs(black_box(maze.start), |p| maze.neighbors(p))));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_search);
+criterion_main!(benches);
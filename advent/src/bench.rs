@@ -0,0 +1,276 @@
+// Timing a day's parse and parts over many runs, with how much memory each needed at most, so the
+// cost of a change can be compared between runs and written out for keeping track over time.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::{Error, Solution};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// The system allocator, keeping count of the bytes in use and the most there have been.
+// Memory can only be measured in a program that makes this its allocator:
+//
+//     #[global_allocator]
+//     static ALLOCATOR: advent::bench::CountingAllocator = advent::bench::CountingAllocator;
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            INSTALLED.store(true, Ordering::Relaxed);
+            let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+// Start measuring the most memory used from now on, returning what's in use to measure from
+fn reset_peak() -> usize {
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    current
+}
+
+// The most memory used on top of start since reset_peak, or None without a CountingAllocator.
+// Other threads' memory is counted too, so it's only exact when nothing else is running.
+fn peak_since(start: usize) -> Option<usize> {
+    INSTALLED.load(Ordering::Relaxed).then(|| PEAK.load(Ordering::Relaxed).saturating_sub(start))
+}
+
+// The fastest, middle and slowest of a number of runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    // With an even number of times, the median is halfway between the middle two
+    pub fn from_times(times: &[Duration]) -> Option<Stats> {
+        let mut times = times.to_vec();
+        times.sort();
+        let n = times.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => times[n / 2],
+            _ => (times[n / 2 - 1] + times[n / 2]) / 2,
+        };
+        Some(Stats { min: times[0], median, max: times[n - 1] })
+    }
+}
+
+// How one step of a day (parsing or a part) went over all the runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub step: &'static str,
+    pub stats: Stats,
+    // the most memory the step allocated in any run, in bytes, if it could be measured
+    pub peak_bytes: Option<usize>,
+    // the answer, for the parts
+    pub answer: Option<String>,
+}
+
+// Run f the given number of times, at least once, returning how it went and what the last run returned.
+// What each run returns is dropped before the next one starts, so it isn't counted against it.
+pub fn measure<T, MeasureFn>(runs: usize, mut f: MeasureFn) -> (Stats, Option<usize>, T)
+where
+    MeasureFn: FnMut() -> T,
+{
+    let mut times = Vec::with_capacity(runs.max(1));
+    let mut peak = None;
+    let mut last = None;
+    for _ in 0..runs.max(1) {
+        drop(last.take());
+        let start_bytes = reset_peak();
+        let start = Instant::now();
+        let value = f();
+        times.push(start.elapsed());
+        peak = peak.max(peak_since(start_bytes));
+        last = Some(value);
+    }
+    (Stats::from_times(&times).unwrap(), peak, last.unwrap())
+}
+
+// Parse the contents and solve each part the given number of times, measuring each separately
+pub fn bench<S: Solution>(contents: &str, runs: usize) -> Result<Vec<Measurement>, Error> {
+    let (stats, peak_bytes, input) = measure(runs, || S::parse(contents));
+    let input = input?;
+    let parse = Measurement { step: "parse", stats, peak_bytes, answer: None };
+    let part = |step, solve: fn(&S::Input) -> String| {
+        let (stats, peak_bytes, answer) = measure(runs, || solve(&input));
+        Measurement { step, stats, peak_bytes, answer: Some(answer) }
+    };
+    Ok(vec![parse, part("part1", S::part1), part("part2", S::part2)])
+}
+
+// The measurements of one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub number: u32,
+    pub name: String,
+    pub measurements: Vec<Measurement>,
+}
+
+// A size in bytes the way people read them, like 1.5 MiB
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+// A table of a day's measurements, one line per step
+pub fn format_report(report: &DayReport) -> String {
+    let mut out = format!("{:<8}{:>14}{:>14}{:>14}{:>14}\n", "", "min", "median", "max", "peak memory");
+    for m in &report.measurements {
+        let peak = m.peak_bytes.map_or("-".to_string(), format_bytes);
+        let times = [m.stats.min, m.stats.median, m.stats.max].map(|time| format!("{:.2?}", time));
+        writeln!(out, "{:<8}{:>14}{:>14}{:>14}{:>14}", m.step, times[0], times[1], times[2], peak).unwrap();
+    }
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// The reports as JSON, with times in nanoseconds and memory in bytes (null where it wasn't measured):
+//
+//     {"runs": 10, "days": [{"day": 1, "name": "day01", "steps": [
+//         {"step": "parse", "min_ns": 1, "median_ns": 2, "max_ns": 3, "peak_bytes": 4, "answer": null}, ...]}]}
+pub fn to_json(runs: usize, reports: &[DayReport]) -> String {
+    let days: Vec<String> = reports
+        .iter()
+        .map(|report| {
+            let steps: Vec<String> = report
+                .measurements
+                .iter()
+                .map(|m| {
+                    format!(
+                        "{{\"step\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}, \"peak_bytes\": {}, \"answer\": {}}}",
+                        json_string(m.step),
+                        m.stats.min.as_nanos(),
+                        m.stats.median.as_nanos(),
+                        m.stats.max.as_nanos(),
+                        m.peak_bytes.map_or("null".to_string(), |bytes| bytes.to_string()),
+                        m.answer.as_deref().map_or("null".to_string(), json_string)
+                    )
+                })
+                .collect();
+            format!(
+                "    {{\"day\": {}, \"name\": {}, \"steps\": [\n      {}\n    ]}}",
+                report.number,
+                json_string(&report.name),
+                steps.join(",\n      ")
+            )
+        })
+        .collect();
+    format!("{{\n  \"runs\": {},\n  \"days\": [\n{}\n  ]\n}}\n", runs, days.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        type Input = Vec<String>;
+
+        fn parse(contents: &str) -> Result<Vec<String>, Error> {
+            Ok(contents.lines().map(String::from).collect())
+        }
+
+        fn part1(input: &Vec<String>) -> String {
+            input.len().to_string()
+        }
+
+        fn part2(input: &Vec<String>) -> String {
+            input.iter().map(|line| line.repeat(1000)).map(|line| line.len()).sum::<usize>().to_string()
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(Stats::from_times(&[ms(5), ms(1), ms(3)]), Some(Stats { min: ms(1), median: ms(3), max: ms(5) }));
+        assert_eq!(Stats::from_times(&[ms(4), ms(1), ms(2), ms(9)]), Some(Stats { min: ms(1), median: ms(3), max: ms(9) }));
+        assert_eq!(Stats::from_times(&[]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let measurements = bench::<Lengths>("ab\ncde\n", 5).unwrap();
+        let steps: Vec<_> = measurements.iter().map(|m| (m.step, m.answer.as_deref())).collect();
+        assert_eq!(steps, vec![("parse", None), ("part1", Some("2")), ("part2", Some("5000"))]);
+        // part 2 builds a 3000 byte string, and other tests running at the same time can only add to that
+        assert!(measurements[2].peak_bytes.unwrap() >= 3000);
+        assert!(measurements.iter().all(|m| m.stats.min <= m.stats.median && m.stats.median <= m.stats.max));
+        // runs always happen at least once
+        let (_, _, value) = measure(0, || 7);
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn test_reports() {
+        let stats = Stats { min: Duration::from_nanos(10), median: Duration::from_nanos(20), max: Duration::from_micros(3) };
+        let report = DayReport {
+            number: 1,
+            name: "day01".to_string(),
+            measurements: vec![
+                Measurement { step: "parse", stats, peak_bytes: Some(1536), answer: None },
+                Measurement { step: "part1", stats, peak_bytes: None, answer: Some("say \"hi\"".to_string()) },
+            ],
+        };
+        assert_eq!(
+            to_json(3, std::slice::from_ref(&report)),
+            concat!(
+                "{\n  \"runs\": 3,\n  \"days\": [\n",
+                "    {\"day\": 1, \"name\": \"day01\", \"steps\": [\n",
+                "      {\"step\": \"parse\", \"min_ns\": 10, \"median_ns\": 20, \"max_ns\": 3000, \"peak_bytes\": 1536, \"answer\": null},\n",
+                "      {\"step\": \"part1\", \"min_ns\": 10, \"median_ns\": 20, \"max_ns\": 3000, \"peak_bytes\": null, \"answer\": \"say \\\"hi\\\"\"}\n",
+                "    ]}\n  ]\n}\n"
+            )
+        );
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+        let table = format_report(&report);
+        assert_eq!(table.lines().nth(1).unwrap(), "parse          10.00ns       20.00ns        3.00µs       1.5 KiB");
+        assert!(table.lines().nth(2).unwrap().ends_with("-"));
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+// Breadth-first search from start, for graphs where every step costs the same.
+// Gives the number of steps to every node that can be reached, with the start at 0.
+pub fn bfs<Node, NeighborFn, Neighbors>(start: Node, get_neighbors: NeighborFn) -> HashMap<Node, usize>
+where
+    Node: Eq + Hash + Clone,
+    NeighborFn: Fn(&Node) -> Neighbors,
+    Neighbors: IntoIterator<Item = Node>,
+{
+    let mut distance = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let steps = distance[&node] + 1;
+        for neighbor in get_neighbors(&node) {
+            if !distance.contains_key(&neighbor) {
+                distance.insert(neighbor.clone(), steps);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    distance
+}
+
+// How many nodes can be reached from start, including start itself
+pub fn reachable_count<Node, NeighborFn, Neighbors>(start: Node, get_neighbors: NeighborFn) -> usize
+where
+    Node: Eq + Hash + Clone,
+    NeighborFn: Fn(&Node) -> Neighbors,
+    Neighbors: IntoIterator<Item = Node>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        for neighbor in get_neighbors(&node) {
+            if seen.insert(neighbor.clone()) {
+                stack.push(neighbor);
+            }
+        }
+    }
+    seen.len()
+}
+
+// Split nodes into connected regions, where two neighbors are in the same region if connected says so.
+// Regions come out in the order of their first node in nodes. Neighbors that aren't in nodes are left out.
+pub fn flood_fill<Node, NeighborFn, Neighbors, ConnectedFn>(nodes: impl IntoIterator<Item = Node>, get_neighbors: NeighborFn, connected: ConnectedFn) -> Vec<HashSet<Node>>
+where
+    Node: Eq + Hash + Clone,
+    NeighborFn: Fn(&Node) -> Neighbors,
+    Neighbors: IntoIterator<Item = Node>,
+    ConnectedFn: Fn(&Node, &Node) -> bool,
+{
+    let nodes: Vec<Node> = nodes.into_iter().collect();
+    let included: HashSet<&Node> = nodes.iter().collect();
+    let mut seen = HashSet::new();
+    let mut regions = Vec::new();
+    for node in &nodes {
+        if seen.contains(node) {
+            continue;
+        }
+        let mut region = HashSet::from([node.clone()]);
+        let mut stack = vec![node.clone()];
+        while let Some(node) = stack.pop() {
+            for neighbor in get_neighbors(&node) {
+                if included.contains(&neighbor) && connected(&node, &neighbor) && region.insert(neighbor.clone()) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+        seen.extend(region.iter().cloned());
+        regions.push(region);
+    }
+    regions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a number line from 0 to 9 where you can step up by one or jump up by three
+    fn steps(n: &u32) -> Vec<u32> {
+        [n + 1, n + 3].into_iter().filter(|&m| m < 10).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let distance = bfs(0, steps);
+        assert_eq!(distance.len(), 10);
+        assert_eq!(distance[&0], 0);
+        assert_eq!(distance[&3], 1);
+        assert_eq!(distance[&5], 3);
+        assert_eq!(distance[&9], 3);
+        assert_eq!(bfs(9, steps), HashMap::from([(9, 0)]));
+        assert_eq!(reachable_count(0, steps), 10);
+        assert_eq!(reachable_count(7, steps), 3);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        // numbers three apart are connected when both are below 5, and the rest are each alone
+        let neighbors = |&n: &i32| [n - 3, n - 1, n + 1, n + 3];
+        let regions = flood_fill(0..8, neighbors, |&a, &b| a < 5 && b < 5 && (a - b).abs() == 3);
+        assert_eq!(regions, vec![
+            HashSet::from([0, 3]),
+            HashSet::from([1, 4]),
+            HashSet::from([2]),
+            HashSet::from([5]),
+            HashSet::from([6]),
+            HashSet::from([7]),
+        ]);
+        // everything connected, but only the given nodes are filled
+        let regions = flood_fill([2, 3, 4, 8, 9], neighbors, |_, _| true);
+        assert_eq!(regions, vec![HashSet::from([2, 3, 4]), HashSet::from([8, 9])]);
+    }
+}
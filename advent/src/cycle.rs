@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Where the sequence start, step(start), step(step(start)), ... starts repeating: after mu steps it
+// reaches first, and from there it comes back to first every lambda steps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<State> {
+    pub mu: usize,
+    pub lambda: usize,
+    pub first: State,
+}
+
+// Find the cycle with Brent's algorithm, which keeps only two states at a time.
+// The sequence must repeat eventually, or this never returns.
+pub fn find_cycle<State, StepFn>(start: State, step: StepFn) -> Cycle<State>
+where
+    State: PartialEq + Clone,
+    StepFn: Fn(&State) -> State,
+{
+    // find lambda by letting the hare run ahead in stretches that double in length,
+    // until it comes back round to where the tortoise waits at the start of a stretch
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = step(&hare);
+        lambda += 1;
+    }
+    // then with the hare lambda steps ahead, they meet at the first state in the cycle
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..lambda {
+        hare = step(&hare);
+    }
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+    Cycle { mu, lambda, first: tortoise }
+}
+
+// Find the cycle with Floyd's algorithm, the hare running at twice the tortoise's speed.
+// This usually takes more steps than Brent's, but also keeps only two states.
+pub fn find_cycle_floyd<State, StepFn>(start: State, step: StepFn) -> Cycle<State>
+where
+    State: PartialEq + Clone,
+    StepFn: Fn(&State) -> State,
+{
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&step(&hare));
+    }
+    // the meeting point is a multiple of lambda steps in, so from there and from the start
+    // the two meet again at the first state in the cycle
+    let mut mu = 0;
+    let mut tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+    let mut lambda = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        lambda += 1;
+    }
+    Cycle { mu, lambda, first: tortoise }
+}
+
+// Find the cycle by remembering every state, which takes the fewest steps, for states that can be hashed
+pub fn find_cycle_hashed<State, StepFn>(start: State, step: StepFn) -> Cycle<State>
+where
+    State: Eq + Hash + Clone,
+    StepFn: Fn(&State) -> State,
+{
+    let mut seen = HashMap::new();
+    let mut state = start;
+    let mut i = 0;
+    loop {
+        if let Some(&mu) = seen.get(&state) {
+            return Cycle { mu, lambda: i - mu, first: state };
+        }
+        let next = step(&state);
+        seen.insert(state, i);
+        state = next;
+        i += 1;
+    }
+}
+
+// The state after n steps, skipping round the cycle once one is found, so n can be huge
+pub fn nth_state<State, StepFn>(step: StepFn, start: State, n: usize) -> State
+where
+    State: Eq + Hash + Clone,
+    StepFn: Fn(&State) -> State,
+{
+    let mut seen: HashMap<State, usize> = HashMap::new();
+    let mut states: Vec<State> = Vec::new();
+    let mut state = start;
+    for i in 0..n {
+        if let Some(&mu) = seen.get(&state) {
+            let lambda = i - mu;
+            return states[mu + (n - mu) % lambda].clone();
+        }
+        seen.insert(state.clone(), i);
+        states.push(state.clone());
+        state = step(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3 steps into a cycle of 5: 0 1 2 | 3 4 5 6 7 | 3 ...
+    fn step(n: &u32) -> u32 {
+        if *n == 7 { 3 } else { n + 1 }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = Cycle { mu: 3, lambda: 5, first: 3 };
+        assert_eq!(find_cycle(0, step), cycle);
+        assert_eq!(find_cycle_floyd(0, step), cycle);
+        assert_eq!(find_cycle_hashed(0, step), cycle);
+        // starting inside the cycle
+        let cycle = Cycle { mu: 0, lambda: 5, first: 5 };
+        assert_eq!(find_cycle(5, step), cycle);
+        assert_eq!(find_cycle_floyd(5, step), cycle);
+        assert_eq!(find_cycle_hashed(5, step), cycle);
+        // a state that stays put is a cycle of one
+        let stay = |n: &u32| (*n + 1).min(4);
+        assert_eq!(find_cycle(0, stay), Cycle { mu: 4, lambda: 1, first: 4 });
+        assert_eq!(find_cycle_floyd(0, stay), Cycle { mu: 4, lambda: 1, first: 4 });
+    }
+
+    #[test]
+    fn test_nth_state() {
+        for n in 0..20 {
+            let walked = (0..n).fold(0, |state, _| step(&state));
+            assert_eq!(nth_state(step, 0, n), walked);
+        }
+        // a billion steps of a sequence with a long cycle, worked out in a moment
+        let lcg = |x: &u64| (x * 1103 + 12345) % 100_003;
+        let n = 1_000_000_000;
+        let cycle = find_cycle_hashed(1, lcg);
+        let skipped = cycle.mu + (n - cycle.mu) % cycle.lambda;
+        assert_eq!(nth_state(lcg, 1, n), (0..skipped).fold(1, |x, _| lcg(&x)));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::Point2D;
+
+// A compass heading on a grid, where north is up (towards smaller y) and east is right.
+// The first four variants are the cardinal directions; the diagonals make it 8-way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+    NorthEast,
+    SouthEast,
+    SouthWest,
+    NorthWest,
+}
+
+use Direction::*;
+
+impl Direction {
+    // The 4-way directions, clockwise from north
+    pub const CARDINAL: [Direction; 4] = [North, East, South, West];
+    // The 8-way directions, clockwise from north
+    pub const ALL: [Direction; 8] = [North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest];
+
+    // how many eighth turns clockwise from north
+    fn eighths(self) -> i32 {
+        Self::ALL.iter().position(|&d| d == self).unwrap() as i32
+    }
+
+    fn from_eighths(eighths: i32) -> Self {
+        Self::ALL[eighths.rem_euclid(8) as usize]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.eighths() % 2 == 1
+    }
+
+    // A quarter turn anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::from_eighths(self.eighths() - 2)
+    }
+
+    // A quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        Self::from_eighths(self.eighths() + 2)
+    }
+
+    // An eighth turn anticlockwise
+    pub fn turn_left_45(self) -> Self {
+        Self::from_eighths(self.eighths() - 1)
+    }
+
+    // An eighth turn clockwise
+    pub fn turn_right_45(self) -> Self {
+        Self::from_eighths(self.eighths() + 1)
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::from_eighths(self.eighths() + 4)
+    }
+
+    // The smallest number of eighth turns either way to get from self to other, from 0 to 4
+    pub fn eighth_turns_to(self, other: Direction) -> u32 {
+        let turns = (other.eighths() - self.eighths()).rem_euclid(8);
+        turns.min(8 - turns) as u32
+    }
+
+    // The smallest number of quarter turns either way to get from self to other, from 0 to 2.
+    // Between a cardinal and a diagonal direction, the leftover eighth turn counts as a whole quarter turn.
+    pub fn quarter_turns_to(self, other: Direction) -> u32 {
+        self.eighth_turns_to(other).div_ceil(2)
+    }
+
+    // The step to take to move one cell in this direction
+    pub fn delta(self) -> Point2D<i32> {
+        match self {
+            North => Point2D::new(0, -1),
+            NorthEast => Point2D::new(1, -1),
+            East => Point2D::new(1, 0),
+            SouthEast => Point2D::new(1, 1),
+            South => Point2D::new(0, 1),
+            SouthWest => Point2D::new(-1, 1),
+            West => Point2D::new(-1, 0),
+            NorthWest => Point2D::new(-1, -1),
+        }
+    }
+
+    // The direction for an arrow as drawn in puzzle inputs, ^ > v < for the cardinal directions
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(North),
+            '>' => Some(East),
+            'v' => Some(South),
+            '<' => Some(West),
+            '↗' => Some(NorthEast),
+            '↘' => Some(SouthEast),
+            '↙' => Some(SouthWest),
+            '↖' => Some(NorthWest),
+            _ => None,
+        }
+    }
+
+    pub fn to_arrow(self) -> char {
+        match self {
+            North => '^',
+            East => '>',
+            South => 'v',
+            West => '<',
+            NorthEast => '↗',
+            SouthEast => '↘',
+            SouthWest => '↙',
+            NorthWest => '↖',
+        }
+    }
+}
+
+impl Point2D<i32> {
+    // The point one step away in the given direction
+    pub fn step(&self, direction: Direction) -> Point2D<i32> {
+        *self + direction.delta()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(West.turn_right(), North);
+        assert_eq!(NorthEast.turn_right(), SouthEast);
+        assert_eq!(North.turn_right_45(), NorthEast);
+        assert_eq!(North.turn_left_45(), NorthWest);
+        assert_eq!(East.reverse(), West);
+        assert_eq!(SouthWest.reverse(), NorthEast);
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.reverse().reverse(), d);
+            assert_eq!(d.turn_right().turn_right(), d.reverse());
+        }
+    }
+
+    #[test]
+    fn test_direction_turn_difference() {
+        assert_eq!(North.quarter_turns_to(North), 0);
+        assert_eq!(North.quarter_turns_to(East), 1);
+        assert_eq!(North.quarter_turns_to(West), 1);
+        assert_eq!(North.quarter_turns_to(South), 2);
+        assert_eq!(West.quarter_turns_to(East), 2);
+        assert_eq!(North.eighth_turns_to(NorthWest), 1);
+        assert_eq!(North.quarter_turns_to(NorthWest), 1);
+        assert_eq!(North.eighth_turns_to(SouthEast), 3);
+    }
+
+    #[test]
+    fn test_direction_arrows_and_deltas() {
+        for d in Direction::ALL {
+            assert_eq!(Direction::from_arrow(d.to_arrow()), Some(d));
+            assert_eq!(d.delta().add(&d.reverse().delta()), Point2D::new(0, 0));
+        }
+        assert_eq!(Direction::from_arrow('.'), None);
+        assert_eq!(Point2D::new(3, 3).step(North), Point2D::new(3, 2));
+        assert_eq!(Point2D::new(3, 3).step(SouthWest), Point2D::new(2, 4));
+        assert!(NorthWest.is_diagonal());
+        assert!(!South.is_diagonal());
+    }
+}
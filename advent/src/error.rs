@@ -0,0 +1,126 @@
+use std::fmt;
+
+// An error reading or parsing puzzle input, with as much context as is known about where it happened.
+// Parsers fill in the line (and column) of the problem, and callers that know the day and the
+// file name add those, so the message reads like "day13 files/input:14: expected `Button B: X+n, Y+n`".
+// Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub message: String,
+    pub day: Option<String>,
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into(), day: None, file: None, line: None, column: None }
+    }
+
+    // An error for something that was expected in the input but not found
+    pub fn expected(what: impl fmt::Display) -> Self {
+        Self::new(format!("expected `{}`", what))
+    }
+
+    // Set the line, unless a more specific one was already set
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    // Set the column, unless a more specific one was already set
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    // Shift the line of an error found while parsing part of the input (such as one section)
+    // so it counts from the start of the whole input. `first_line` is where the part starts.
+    pub fn offset_line(mut self, first_line: usize) -> Self {
+        self.line = Some(self.line.map_or(first_line, |line| line + first_line - 1));
+        self
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file.get_or_insert(file.into());
+        self
+    }
+
+    pub fn in_day(mut self, day: impl Into<String>) -> Self {
+        self.day.get_or_insert(day.into());
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = &self.day {
+            write!(f, "{} ", day)?;
+        }
+        // file:line:column when the file is known, like a compiler, otherwise spell out what the numbers are
+        match (&self.file, self.line, self.column) {
+            (Some(file), Some(line), Some(column)) => write!(f, "{}:{}:{}: ", file, line, column)?,
+            (Some(file), Some(line), None) => write!(f, "{}:{}: ", file, line)?,
+            (Some(file), None, _) => write!(f, "{}: ", file)?,
+            (None, Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (None, Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None, None) => {}
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::new(error.to_string())
+    }
+}
+
+// Parse a string into a number (or anything else parseable), with the parse error as the message
+pub fn parse_value<T>(s: &str) -> Result<T, Error>
+where
+    T: std::str::FromStr,
+    T::Err: fmt::Display,
+{
+    s.trim().parse::<T>().map_err(|e| Error::new(format!("can't parse `{}`: {}", s.trim(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_display() {
+        let error = Error::expected("Button B: X+n, Y+n");
+        assert_eq!(error.to_string(), "expected `Button B: X+n, Y+n`");
+        let error = error.at_line(14);
+        assert_eq!(error.to_string(), "line 14: expected `Button B: X+n, Y+n`");
+        let error = error.in_file("files/input").in_day("day13");
+        assert_eq!(error.to_string(), "day13 files/input:14: expected `Button B: X+n, Y+n`");
+        let error = Error::new("bad").at_line(3).at_column(7).in_file("files/test");
+        assert_eq!(error.to_string(), "files/test:3:7: bad");
+        let error = Error::new("no such file").in_file("files/input");
+        assert_eq!(error.to_string(), "files/input: no such file");
+    }
+
+    #[test]
+    fn test_error_line_context() {
+        // the innermost line wins over outer context
+        assert_eq!(Error::new("x").at_line(2).at_line(5).line, Some(2));
+        // a line inside a section that starts at line 10 of the input
+        assert_eq!(Error::new("x").at_line(2).offset_line(10).line, Some(11));
+        // an error for a whole section is reported at its first line
+        assert_eq!(Error::new("x").offset_line(10).line, Some(10));
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value::<i32>(" 42"), Ok(42));
+        let error = parse_value::<i32>("4x2").unwrap_err();
+        assert_eq!(error.message, "can't parse `4x2`: invalid digit found in string");
+    }
+}
@@ -0,0 +1,128 @@
+use std::path::{Path, PathBuf};
+
+// An example input in a day's files directory, paired with the expected answer for one part.
+// The input is any file <name>, and its answers are in files named <name>_answer_<part>.
+// An answer the solution is known not to give yet, such as for a part that isn't solved, is kept
+// with a file <name>_answer_<part>_ignore next to it saying why, and its test is ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub name: String,
+    pub part: u32,
+    pub input: PathBuf,
+    pub answer: PathBuf,
+    pub ignored: Option<String>,
+}
+
+// Find every input/answer pair in a files directory, sorted by input name and part.
+// Answer files without a matching input file are ignored.
+pub fn discover(files_dir: &Path) -> Vec<Fixture> {
+    let mut fixtures = Vec::new();
+    let entries = match std::fs::read_dir(files_dir) {
+        Ok(entries) => entries,
+        Err(_) => return fixtures,
+    };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some((name, part)) = file_name.rsplit_once("_answer_") else {
+            continue;
+        };
+        let Ok(part) = part.parse::<u32>() else {
+            continue;
+        };
+        let input = files_dir.join(name);
+        if input.is_file() {
+            let ignored = std::fs::read_to_string(files_dir.join(format!("{}_ignore", file_name)))
+                .ok()
+                .map(|reason| reason.trim().to_string());
+            fixtures.push(Fixture { name: name.to_string(), part, input, answer: entry.path(), ignored });
+        }
+    }
+    fixtures.sort_by(|a, b| (&a.name, a.part).cmp(&(&b.name, b.part)));
+    fixtures
+}
+
+impl Fixture {
+    // Solve the example with the given solver, and compare against the expected answer.
+    // A trailing newline in the answer file is not part of the answer.
+    pub fn check(&self, solve: fn(&str, &[u32]) -> Result<Vec<crate::PartResult>, crate::Error>) -> Result<(), String> {
+        let input = self.input.to_string_lossy();
+        let result = crate::try_read_file_to_string(&input)
+            .and_then(|contents| solve(&contents, &[self.part]))
+            .map_err(|e| e.in_file(input.as_ref()).to_string())?;
+        let expected = crate::try_read_file_to_string(&self.answer.to_string_lossy()).map_err(|e| e.to_string())?;
+        let expected = expected.trim_end_matches('\n');
+        if result[0].answer == expected {
+            Ok(())
+        } else {
+            Err(format!("part {} of {} was {}, expected {}", self.part, self.name, result[0].answer, expected))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // make an empty directory under the system temp directory for a test to fill with files
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("advent-fixtures-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_discover_pairs() {
+        let dir = scratch_dir("pairs");
+        for (file, contents) in [
+            ("test", "1 2"),
+            ("test_answer_1", "3"),
+            ("test_answer_2", "2"),
+            ("test_big", "4 5"),
+            ("test_big_answer_2", "20"),
+            ("test_small", "6"),
+            ("missing_answer_1", "7"),
+            ("input", "8 9"),
+            ("test_answer_2_ignore", "not solved yet\n"),
+        ] {
+            std::fs::write(dir.join(file), contents).unwrap();
+        }
+        let found: Vec<(String, u32, Option<String>)> = discover(&dir).into_iter().map(|f| (f.name, f.part, f.ignored)).collect();
+        assert_eq!(found, vec![
+            ("test".to_string(), 1, None),
+            ("test".to_string(), 2, Some("not solved yet".to_string())),
+            ("test_big".to_string(), 2, None),
+        ]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_discover_missing_directory() {
+        assert!(discover(Path::new("no/such/files")).is_empty());
+    }
+
+    #[test]
+    fn test_check() {
+        fn solve(contents: &str, parts: &[u32]) -> Result<Vec<crate::PartResult>, crate::Error> {
+            if contents.starts_with('!') {
+                return Err(crate::Error::expected("a letter").at_line(1));
+            }
+            Ok(parts.iter().map(|&part| crate::PartResult {
+                part,
+                answer: (contents.len() as u32 * part).to_string(),
+                duration: std::time::Duration::ZERO,
+            }).collect())
+        }
+        let dir = scratch_dir("check");
+        std::fs::write(dir.join("test"), "abc").unwrap();
+        std::fs::write(dir.join("test_answer_1"), "3\n").unwrap();
+        std::fs::write(dir.join("test_answer_2"), "5").unwrap();
+        let fixtures = discover(&dir);
+        assert_eq!(fixtures[0].check(solve), Ok(()));
+        assert_eq!(fixtures[1].check(solve), Err("part 2 of test was 6, expected 5".to_string()));
+        std::fs::write(dir.join("test"), "!bc").unwrap();
+        let input = dir.join("test").to_string_lossy().to_string();
+        assert_eq!(fixtures[0].check(solve), Err(format!("{}:1: expected `a letter`", input)));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
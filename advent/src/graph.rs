@@ -0,0 +1,442 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+const UNVISITED: usize = usize::MAX;
+
+// A graph stored as adjacency lists. Nodes keep the order they were added in, and the
+// algorithms use that order to break ties, so their results don't change from run to run.
+// An undirected graph stores each edge both ways round.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    directed: bool,
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    out_edges: Vec<Vec<usize>>,
+    in_edges: Vec<Vec<usize>>,
+    arcs: HashSet<(usize, usize)>,
+    edge_count: usize,
+}
+
+impl<N> Graph<N>
+where
+    N: Eq + Hash + Clone,
+{
+    pub fn directed() -> Self {
+        Graph::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Graph::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Graph { directed, nodes: Vec::new(), index: HashMap::new(), out_edges: Vec::new(), in_edges: Vec::new(), arcs: HashSet::new(), edge_count: 0 }
+    }
+
+    // A directed graph with an edge from a to b for each (a, b)
+    pub fn directed_from(edges: impl IntoIterator<Item = (N, N)>) -> Self {
+        let mut graph = Graph::directed();
+        graph.extend(edges);
+        graph
+    }
+
+    // An undirected graph with an edge between a and b for each (a, b)
+    pub fn undirected_from(edges: impl IntoIterator<Item = (N, N)>) -> Self {
+        let mut graph = Graph::undirected();
+        graph.extend(edges);
+        graph
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    // Add a node with no edges, if it isn't already there
+    pub fn add_node(&mut self, node: N) {
+        self.node_index(node);
+    }
+
+    // Add an edge from a to b, adding the nodes too if they aren't there. Adding an edge twice does nothing.
+    pub fn add_edge(&mut self, a: N, b: N) {
+        let a = self.node_index(a);
+        let b = self.node_index(b);
+        if self.add_arc(a, b) {
+            self.edge_count += 1;
+            if !self.directed {
+                self.add_arc(b, a);
+            }
+        }
+    }
+
+    pub fn extend(&mut self, edges: impl IntoIterator<Item = (N, N)>) {
+        for (a, b) in edges {
+            self.add_edge(a, b);
+        }
+    }
+
+    fn node_index(&mut self, node: N) -> usize {
+        if let Some(&i) = self.index.get(&node) {
+            return i;
+        }
+        let i = self.nodes.len();
+        self.index.insert(node.clone(), i);
+        self.nodes.push(node);
+        self.out_edges.push(Vec::new());
+        self.in_edges.push(Vec::new());
+        i
+    }
+
+    fn add_arc(&mut self, a: usize, b: usize) -> bool {
+        if !self.arcs.insert((a, b)) {
+            return false;
+        }
+        self.out_edges[a].push(b);
+        self.in_edges[b].push(a);
+        true
+    }
+
+    // The number of nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    // The number of edges, counting an undirected edge once
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    // The nodes in the order they were added
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    pub fn has_edge(&self, a: &N, b: &N) -> bool {
+        match (self.index.get(a), self.index.get(b)) {
+            (Some(&a), Some(&b)) => self.arcs.contains(&(a, b)),
+            _ => false,
+        }
+    }
+
+    // The nodes an edge from node leads to, which for an undirected graph is all its neighbors
+    pub fn neighbors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.lookup(&self.out_edges, node)
+    }
+
+    // The nodes with an edge leading to node
+    pub fn predecessors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.lookup(&self.in_edges, node)
+    }
+
+    fn lookup<'a>(&'a self, edges: &'a [Vec<usize>], node: &N) -> impl Iterator<Item = &'a N> {
+        let list = self.index.get(node).map_or(&[][..], |&i| &edges[i][..]);
+        list.iter().map(|&i| &self.nodes[i])
+    }
+
+    pub fn out_degree(&self, node: &N) -> usize {
+        self.index.get(node).map_or(0, |&i| self.out_edges[i].len())
+    }
+
+    pub fn in_degree(&self, node: &N) -> usize {
+        self.index.get(node).map_or(0, |&i| self.in_edges[i].len())
+    }
+
+    // The number of edges touching node: its neighbors in an undirected graph, or in and out edges in a directed one
+    pub fn degree(&self, node: &N) -> usize {
+        if self.directed {
+            self.in_degree(node) + self.out_degree(node)
+        } else {
+            self.out_degree(node)
+        }
+    }
+
+    // The graph with just the given nodes and the edges between them. Nodes that aren't
+    // in this graph are still added, with no edges.
+    pub fn subgraph(&self, nodes: impl IntoIterator<Item = N>) -> Graph<N> {
+        let mut graph = Graph::new(self.directed);
+        for node in nodes {
+            graph.add_node(node);
+        }
+        for a in 0..graph.nodes.len() {
+            let Some(&old_a) = self.index.get(&graph.nodes[a]) else { continue };
+            for &old_b in &self.out_edges[old_a] {
+                if let Some(&b) = graph.index.get(&self.nodes[old_b]) {
+                    if graph.add_arc(a, b) && (self.directed || a <= b) {
+                        graph.edge_count += 1;
+                    }
+                }
+            }
+        }
+        graph
+    }
+
+    // Every node, ordered so each edge goes from an earlier node to a later one, using Kahn's algorithm.
+    // Where there's a choice the node added first goes first. None if there's a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<N>> {
+        let mut in_degree: Vec<usize> = self.in_edges.iter().map(|edges| edges.len()).collect();
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..self.len()).filter(|&i| in_degree[i] == 0).map(Reverse).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(Reverse(node)) = ready.pop() {
+            order.push(self.nodes[node].clone());
+            for &next in &self.out_edges[node] {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    ready.push(Reverse(next));
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    // Groups of nodes that can all reach each other, using Tarjan's algorithm. The groups come out
+    // in topological order, so edges between groups only go from earlier groups to later ones.
+    // For an undirected graph these are the connected components.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut count = 0;
+        let mut components = Vec::new();
+        for root in 0..self.len() {
+            if index[root] != UNVISITED {
+                continue;
+            }
+            index[root] = count;
+            low[root] = count;
+            count += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            // the depth-first walk keeps its own stack of nodes and how many of their edges it has
+            // followed, so a long chain of nodes can't overflow the real one
+            let mut walk = vec![(root, 0)];
+            while let Some((node, edge)) = walk.pop() {
+                if let Some(&next) = self.out_edges[node].get(edge) {
+                    walk.push((node, edge + 1));
+                    if index[next] == UNVISITED {
+                        index[next] = count;
+                        low[next] = count;
+                        count += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        walk.push((next, 0));
+                    } else if on_stack[next] {
+                        low[node] = low[node].min(index[next]);
+                    }
+                    continue;
+                }
+                // every edge followed, so if nothing below reached further back this starts a component
+                if low[node] == index[node] {
+                    let mut component = Vec::new();
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(self.nodes[member].clone());
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.reverse();
+                    components.push(component);
+                }
+                if let Some(&(parent, _)) = walk.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+            }
+        }
+        // Tarjan finds the components that nothing else is reachable from first
+        components.reverse();
+        components
+    }
+
+    // Groups of nodes joined by edges, ignoring which way the edges go. The groups come out
+    // in the order of their first node, and the nodes in each in breadth-first order.
+    pub fn connected_components(&self) -> Vec<Vec<N>> {
+        let mut seen = vec![false; self.len()];
+        let mut components = Vec::new();
+        for start in 0..self.len() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut component = Vec::new();
+            let mut queue = VecDeque::from([start]);
+            while let Some(node) = queue.pop_front() {
+                component.push(self.nodes[node].clone());
+                for &next in self.out_edges[node].iter().chain(&self.in_edges[node]) {
+                    if !seen[next] {
+                        seen[next] = true;
+                        queue.push_back(next);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    // Every maximal clique: groups of nodes that are all neighbors of each other, and that no
+    // other node could join. Found with the Bron–Kerbosch algorithm, pivoting on the node with the
+    // most candidate neighbors. Meant for undirected graphs. Each clique lists its nodes in the
+    // order they were added, and the cliques are sorted by those orders.
+    pub fn maximal_cliques(&self) -> Vec<Vec<N>> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        let neighbors = self.neighbor_sets();
+        let mut cliques = Vec::new();
+        let candidates = (0..self.len()).collect();
+        self.bron_kerbosch(&neighbors, &mut Vec::new(), candidates, HashSet::new(), &mut cliques);
+        for clique in cliques.iter_mut() {
+            clique.sort();
+        }
+        cliques.sort();
+        cliques.into_iter().map(|clique| self.to_nodes(&clique)).collect()
+    }
+
+    // The biggest clique, the first of them if there's a tie. Empty if the graph is.
+    pub fn maximum_clique(&self) -> Vec<N> {
+        let cliques = self.maximal_cliques();
+        let size = cliques.iter().map(|clique| clique.len()).max().unwrap_or(0);
+        cliques.into_iter().find(|clique| clique.len() == size).unwrap_or_default()
+    }
+
+    // Every clique of exactly k nodes, whether or not it's part of a bigger one, in the same order as maximal_cliques
+    pub fn cliques(&self, k: usize) -> Vec<Vec<N>> {
+        let neighbors = self.neighbor_sets();
+        let mut cliques = Vec::new();
+        self.grow_cliques(&neighbors, &mut Vec::new(), (0..self.len()).collect(), k, &mut cliques);
+        cliques.into_iter().map(|clique| self.to_nodes(&clique)).collect()
+    }
+
+    // Each node's neighbors, leaving out any edge from a node to itself
+    fn neighbor_sets(&self) -> Vec<HashSet<usize>> {
+        self.out_edges.iter().enumerate().map(|(node, edges)| edges.iter().copied().filter(|&next| next != node).collect()).collect()
+    }
+
+    fn to_nodes(&self, indexes: &[usize]) -> Vec<N> {
+        indexes.iter().map(|&i| self.nodes[i].clone()).collect()
+    }
+
+    // Grow clique with each of the candidates that could join it. Excluded nodes could also join,
+    // but every clique with them in has already been found.
+    fn bron_kerbosch(&self, neighbors: &[HashSet<usize>], clique: &mut Vec<usize>, mut candidates: HashSet<usize>, mut excluded: HashSet<usize>, cliques: &mut Vec<Vec<usize>>) {
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                cliques.push(clique.clone());
+            }
+            return;
+        }
+        // any maximal clique has either the pivot or a node that isn't its neighbor,
+        // so only those need trying
+        let pivot = candidates.iter().chain(&excluded).max_by_key(|&&node| (neighbors[node].intersection(&candidates).count(), Reverse(node))).copied().unwrap();
+        let mut tries: Vec<usize> = candidates.iter().copied().filter(|node| !neighbors[pivot].contains(node)).collect();
+        tries.sort();
+        for node in tries {
+            clique.push(node);
+            let next_candidates = candidates.iter().copied().filter(|n| neighbors[node].contains(n)).collect();
+            let next_excluded = excluded.iter().copied().filter(|n| neighbors[node].contains(n)).collect();
+            self.bron_kerbosch(neighbors, clique, next_candidates, next_excluded, cliques);
+            clique.pop();
+            candidates.remove(&node);
+            excluded.insert(node);
+        }
+    }
+
+    // Grow clique to k nodes from the candidates, which are all neighbors of the whole clique and come
+    // after it in order, so each clique is only found once
+    fn grow_cliques(&self, neighbors: &[HashSet<usize>], clique: &mut Vec<usize>, candidates: Vec<usize>, k: usize, cliques: &mut Vec<Vec<usize>>) {
+        if clique.len() == k {
+            cliques.push(clique.clone());
+            return;
+        }
+        for (i, &node) in candidates.iter().enumerate() {
+            if clique.len() + candidates.len() - i < k {
+                break;
+            }
+            clique.push(node);
+            let next_candidates = candidates[i + 1..].iter().copied().filter(|n| neighbors[node].contains(n)).collect();
+            self.grow_cliques(neighbors, clique, next_candidates, k, cliques);
+            clique.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directed_graph() {
+        // 1 -> 2 -> 3 -> 1 is a cycle, which leads on to 4 <-> 5, and 6 stands alone
+        let mut graph = Graph::directed_from([(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4), (1, 2)]);
+        graph.add_node(6);
+        assert!(graph.is_directed());
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.edge_count(), 6);
+        assert!(graph.has_edge(&3, &4));
+        assert!(!graph.has_edge(&4, &3));
+        assert_eq!(graph.neighbors(&3).collect::<Vec<_>>(), vec![&1, &4]);
+        assert_eq!(graph.predecessors(&4).collect::<Vec<_>>(), vec![&3, &5]);
+        assert_eq!((graph.out_degree(&3), graph.in_degree(&3), graph.degree(&3)), (2, 1, 3));
+        assert_eq!(graph.degree(&7), 0);
+        assert_eq!(graph.topological_sort(), None);
+        assert_eq!(graph.strongly_connected_components(), vec![vec![6], vec![1, 2, 3], vec![4, 5]]);
+        assert_eq!(graph.connected_components(), vec![vec![1, 2, 3, 4, 5], vec![6]]);
+        // without the cycles
+        let dag = graph.subgraph([1, 3, 4, 6]);
+        assert_eq!(dag.edge_count(), 2);
+        assert_eq!(dag.topological_sort(), Some(vec![3, 1, 4, 6]));
+        let dag = graph.subgraph([4, 2, 3, 6]);
+        assert_eq!(dag.topological_sort(), Some(vec![2, 3, 4, 6]));
+    }
+
+    #[test]
+    fn test_undirected_graph() {
+        let graph = Graph::undirected_from([("a", "b"), ("b", "c"), ("c", "a"), ("b", "a"), ("d", "e")]);
+        assert!(!graph.is_directed());
+        assert_eq!(graph.edge_count(), 4);
+        assert!(graph.has_edge(&"a", &"c") && graph.has_edge(&"c", &"a"));
+        assert_eq!(graph.degree(&"b"), 2);
+        assert_eq!(graph.neighbors(&"b").collect::<Vec<_>>(), vec![&"a", &"c"]);
+        assert_eq!(graph.connected_components(), vec![vec!["a", "b", "c"], vec!["d", "e"]]);
+        let mut components = graph.strongly_connected_components();
+        components.iter_mut().for_each(|component| component.sort());
+        components.sort();
+        assert_eq!(components, vec![vec!["a", "b", "c"], vec!["d", "e"]]);
+        let pair = graph.subgraph(["c", "b", "e"]);
+        assert_eq!(pair.edge_count(), 1);
+        assert_eq!(pair.connected_components(), vec![vec!["c", "b"], vec!["e"]]);
+    }
+
+    #[test]
+    fn test_cliques() {
+        // a square a-b-c-d with both diagonals is a clique of four, plus a triangle c-e-f and an edge f-g
+        let graph = Graph::undirected_from([
+            ("a", "b"), ("b", "c"), ("c", "d"), ("d", "a"), ("a", "c"), ("b", "d"),
+            ("c", "e"), ("e", "f"), ("f", "c"), ("f", "g"),
+        ]);
+        assert_eq!(graph.maximal_cliques(), vec![vec!["a", "b", "c", "d"], vec!["c", "e", "f"], vec!["f", "g"]]);
+        assert_eq!(graph.maximum_clique(), vec!["a", "b", "c", "d"]);
+        assert_eq!(graph.cliques(3), vec![
+            vec!["a", "b", "c"], vec!["a", "b", "d"], vec!["a", "c", "d"], vec!["b", "c", "d"], vec!["c", "e", "f"],
+        ]);
+        assert_eq!(graph.cliques(4).len(), 1);
+        assert!(graph.cliques(5).is_empty());
+        assert_eq!(graph.cliques(2).len(), graph.edge_count());
+        // a node on its own is a clique of one
+        let mut graph = Graph::undirected();
+        graph.add_node(1);
+        assert_eq!(graph.maximal_cliques(), vec![vec![1]]);
+        assert!(Graph::<i32>::undirected().maximal_cliques().is_empty());
+        assert!(Graph::<i32>::undirected().maximum_clique().is_empty());
+    }
+}
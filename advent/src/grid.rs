@@ -0,0 +1,281 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Direction, Error, Point2D};
+
+// A rectangular grid of cells, addressed by Point2D<i32> with (0,0) at the top left and y growing down.
+// Points are signed so that stepping off an edge gives a point that is simply out of bounds.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: i32,
+    height: i32,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // A width by height grid with every cell set to value
+    pub fn new(width: i32, height: i32, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { width, height, cells: vec![value; (width * height) as usize] }
+    }
+
+    // Parse one cell per character, one row per line. Every row must be the same width.
+    pub fn parse<ParseFn>(input: &str, parse_cell: ParseFn) -> Result<Self, Error>
+    where
+        ParseFn: Fn(char) -> Result<T, Error>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (y, line) in input.trim_end_matches('\n').lines().enumerate() {
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                cells.push(parse_cell(c).map_err(|e| e.at_line(y + 1).at_column(x + 1))?);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let message = format!("expected a row of {} cells, found {}", width, row_width);
+                    return Err(Error::new(message).at_line(y + 1));
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+        Ok(Self { width: width.unwrap_or(0), height, cells })
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn in_bounds(&self, p: Point2D<i32>) -> bool {
+        p.x >= 0 && p.x < self.width && p.y >= 0 && p.y < self.height
+    }
+
+    fn offset(&self, p: Point2D<i32>) -> Option<usize> {
+        self.in_bounds(p).then(|| (p.y * self.width + p.x) as usize)
+    }
+
+    // The cell at p, or None if p is off the grid
+    pub fn get(&self, p: Point2D<i32>) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point2D<i32>) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    // Set the cell at p, returning false (and changing nothing) if p is off the grid
+    pub fn set(&mut self, p: Point2D<i32>, value: T) -> bool {
+        match self.get_mut(p) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Every point on the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point2D<i32>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point2D::new(x, y)))
+    }
+
+    // Every point on the grid with its cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D<i32>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    // The up to four points next to p, up, right, down and left of it, that are on the grid
+    pub fn neighbors4(&self, p: Point2D<i32>) -> impl Iterator<Item = Point2D<i32>> + '_ {
+        self.steps(p, &Direction::CARDINAL)
+    }
+
+    // The up to eight points around p, including diagonals, that are on the grid
+    pub fn neighbors8(&self, p: Point2D<i32>) -> impl Iterator<Item = Point2D<i32>> + '_ {
+        self.steps(p, &Direction::ALL)
+    }
+
+    fn steps<'a>(&'a self, p: Point2D<i32>, directions: &'static [Direction]) -> impl Iterator<Item = Point2D<i32>> + 'a {
+        directions.iter()
+            .map(move |&d| p.step(d))
+            .filter(|n| self.in_bounds(*n))
+    }
+
+    // The first point, row by row, whose cell is value
+    pub fn find(&self, value: &T) -> Option<Point2D<i32>>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, cell)| *cell == value).map(|(p, _)| p)
+    }
+
+    // Every point whose cell is value
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point2D<i32>> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(p, _)| p)
+    }
+
+    // The number of steps from start to every point that can be reached, where can_step says whether
+    // you can step from one cell onto the next one up, down, left or right
+    pub fn bfs<StepFn>(&self, start: Point2D<i32>, can_step: StepFn) -> HashMap<Point2D<i32>, usize>
+    where
+        StepFn: Fn(&T, &T) -> bool,
+    {
+        let can_step = &can_step;
+        crate::bfs(start, |&p| self.neighbors4(p).filter(move |&n| can_step(&self[p], &self[n])))
+    }
+
+    // How many points can be reached from start, including start, stepping as for bfs
+    pub fn reachable_count<StepFn>(&self, start: Point2D<i32>, can_step: StepFn) -> usize
+    where
+        StepFn: Fn(&T, &T) -> bool,
+    {
+        let can_step = &can_step;
+        crate::reachable_count(start, |&p| self.neighbors4(p).filter(move |&n| can_step(&self[p], &self[n])))
+    }
+
+    // Split the grid into regions of points joined up, down, left or right, where same says
+    // whether two cells next to each other are in the same region
+    pub fn regions<SameFn>(&self, same: SameFn) -> Vec<HashSet<Point2D<i32>>>
+    where
+        SameFn: Fn(&T, &T) -> bool,
+    {
+        crate::flood_fill(self.points(), |&p| self.neighbors4(p), |&p, &n| same(&self[p], &self[n]))
+    }
+
+    // A grid of the same size with each cell transformed
+    pub fn map<U, MapFn>(&self, f: MapFn) -> Grid<U>
+    where
+        MapFn: Fn(Point2D<i32>, &T) -> U,
+    {
+        Grid { width: self.width, height: self.height, cells: self.iter().map(|(p, cell)| f(p, cell)).collect() }
+    }
+}
+
+impl Grid<char> {
+    // The grid exactly as it is drawn in the input
+    pub fn parse_chars(input: &str) -> Result<Self, Error> {
+        Self::parse(input, Ok)
+    }
+}
+
+impl<T> Index<Point2D<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point2D<i32>) -> &T {
+        self.get(p).unwrap_or_else(|| panic!("({}, {}) is off the {}x{} grid", p.x, p.y, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point2D<i32>> for Grid<T> {
+    fn index_mut(&mut self, p: Point2D<i32>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p).unwrap_or_else(|| panic!("({}, {}) is off the {}x{} grid", p.x, p.y, width, height))
+    }
+}
+
+// Print the grid back out as text, one line per row
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (p, cell) in self.iter() {
+            write!(f, "{}", cell)?;
+            if p.x == self.width - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "#####\n#S..#\n#.#E#\n#####\n";
+
+    #[test]
+    fn test_grid_parse_and_print() {
+        let grid = Grid::parse_chars(MAZE).unwrap();
+        assert_eq!(grid.width(), 5);
+        assert_eq!(grid.height(), 4);
+        assert_eq!(grid.to_string(), MAZE);
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10).ok_or_else(|| Error::expected("a digit"))).unwrap();
+        assert_eq!(digits[Point2D::new(1, 1)], 4);
+        assert_eq!(Grid::parse_chars("").unwrap().width(), 0);
+    }
+
+    #[test]
+    fn test_grid_parse_errors() {
+        let error = Grid::parse_chars("###\n##\n###").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: expected a row of 3 cells, found 2");
+        let error = Grid::parse("12\n3x", |c| c.to_digit(10).ok_or_else(|| Error::expected("a digit"))).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: expected `a digit`");
+    }
+
+    #[test]
+    fn test_grid_get_set() {
+        let mut grid = Grid::new(3, 2, '.');
+        assert!(grid.set(Point2D::new(2, 1), '#'));
+        assert!(!grid.set(Point2D::new(3, 1), '#'));
+        assert_eq!(grid.get(Point2D::new(2, 1)), Some(&'#'));
+        assert_eq!(grid.get(Point2D::new(-1, 0)), None);
+        assert_eq!(grid.get(Point2D::new(0, 2)), None);
+        grid[Point2D::new(0, 0)] = '@';
+        assert_eq!(grid.to_string(), "@..\n..#\n");
+    }
+
+    #[test]
+    fn test_grid_neighbors() {
+        let grid = Grid::parse_chars(MAZE).unwrap();
+        let corner: Vec<_> = grid.neighbors4(Point2D::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point2D::new(1, 0), Point2D::new(0, 1)]);
+        assert_eq!(grid.neighbors4(Point2D::new(2, 2)).count(), 4);
+        assert_eq!(grid.neighbors8(Point2D::new(2, 2)).count(), 8);
+        assert_eq!(grid.neighbors8(Point2D::new(4, 3)).count(), 3);
+        let open: Vec<_> = grid.neighbors4(Point2D::new(1, 1)).filter(|&p| grid[p] != '#').collect();
+        assert_eq!(open, vec![Point2D::new(2, 1), Point2D::new(1, 2)]);
+    }
+
+    #[test]
+    fn test_grid_find() {
+        let grid = Grid::parse_chars(MAZE).unwrap();
+        assert_eq!(grid.find(&'S'), Some(Point2D::new(1, 1)));
+        assert_eq!(grid.find(&'E'), Some(Point2D::new(3, 2)));
+        assert_eq!(grid.find(&'X'), None);
+        assert_eq!(grid.find_all(&'.').count(), 3);
+        let walls = grid.map(|_, &c| u8::from(c == '#'));
+        assert_eq!(walls.to_string(), "11111\n10001\n10101\n11111\n");
+    }
+
+    #[test]
+    fn test_grid_bfs_and_regions() {
+        let grid = Grid::parse_chars(MAZE).unwrap();
+        let start = grid.find(&'S').unwrap();
+        let distance = grid.bfs(start, |_, &next| next != '#');
+        assert_eq!(distance.len(), 5);
+        assert_eq!(distance[&grid.find(&'E').unwrap()], 3);
+        assert_eq!(grid.reachable_count(start, |_, &next| next != '#'), 5);
+        // a cell can be reached only by stepping from a lower number to the next one up
+        let heights = Grid::parse("012\n343\n", |c| c.to_digit(10).ok_or_else(|| Error::expected("a digit"))).unwrap();
+        let distance = heights.bfs(Point2D::new(0, 0), |&from, &to| to == from + 1);
+        assert_eq!(distance.get(&Point2D::new(1, 1)), Some(&4));
+        assert_eq!(distance.get(&Point2D::new(0, 1)), None);
+        let regions = grid.regions(|a, b| (*a == '#') == (*b == '#'));
+        // the wall in the middle joins the outer wall
+        assert_eq!(regions.iter().map(|region| region.len()).collect::<Vec<_>>(), vec![15, 5]);
+        assert!(regions[1].contains(&start));
+    }
+}
@@ -0,0 +1,380 @@
+use std::fmt;
+
+// define a generic interval type
+// An interval covers start up to but not including end, so [1,3) and [3,5) touch without overlapping,
+// and the length is end - start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T: Ord + Copy> Interval<T> {
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> T
+    where
+        T: std::ops::Sub<Output = T>,
+    {
+        self.end - self.start
+    }
+
+    pub fn contains_value(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    // true if every value in other is in self
+    pub fn contains(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    // true if there is a value in both intervals
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    // true if the intervals overlap or one ends where the other starts, so their union is one interval
+    pub fn touches(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    // The values in both intervals, or None if there are none
+    pub fn intersect(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        self.overlaps(other)
+            .then(|| Interval::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+
+    // The values in either interval, or None if that isn't a single interval because there is a gap between them
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        self.touches(other)
+            .then(|| Interval::new(self.start.min(other.start), self.end.max(other.end)))
+    }
+
+    // The values in self but not in other, as zero, one or two intervals
+    pub fn difference(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+        [Interval::new(self.start, other.start), Interval::new(other.end, self.end)]
+            .into_iter()
+            .filter(|piece| !piece.is_empty())
+            .collect()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+// intersection
+pub fn intersect<T: PartialOrd + Copy + Ord>(a: Interval<T>, b: Interval<T>) -> Option<Interval<T>> {
+    a.intersect(&b)
+}
+
+// union, which is None for intervals with a gap between them
+pub fn union<T: PartialOrd + Copy + Ord>(a: Interval<T>, b: Interval<T>) -> Option<Interval<T>> {
+    a.union(&b)
+}
+
+// difference
+pub fn difference<T: PartialOrd + Copy + Ord>(a: Interval<T>, b: Interval<T>) -> Vec<Interval<T>> {
+    a.difference(&b)
+}
+
+// contains
+pub fn contains<T: PartialOrd + Copy>(a: Interval<T>, b: Interval<T>) -> bool {
+    a.start <= b.start && a.end >= b.end
+}
+
+// equals
+pub fn equals<T: PartialEq + Copy>(a: Interval<T>, b: Interval<T>) -> bool {
+    a.start == b.start && a.end == b.end
+}
+
+// to_string, the same as Display: half open, like [1, 5)
+pub fn to_string<T: fmt::Display>(interval: Interval<T>) -> String {
+    interval.to_string()
+}
+
+// less
+pub fn less<T: PartialOrd + Copy>(a: Interval<T>, b: Interval<T>) -> bool {
+    a.start < b.start || (a.start == b.start && a.end < b.end)
+}
+
+// A set of values kept as sorted, disjoint intervals. Intervals that overlap or touch are merged,
+// so there is exactly one way to hold any set, and two sets with the same values are equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: Vec::new() }
+    }
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The intervals in order
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    // The number of separate intervals, not the number of values
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // the intervals that touch the new one are a run, which merges with it into one interval
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, i| merged.union(i).unwrap());
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // only the intervals that overlap the removed one change, and each leaves at most two pieces
+        let first = self.intervals.partition_point(|i| i.end <= interval.start);
+        let last = self.intervals.partition_point(|i| i.start < interval.end);
+        let pieces: Vec<Interval<T>> = self.intervals[first..last]
+            .iter()
+            .flat_map(|i| i.difference(&interval))
+            .collect();
+        self.intervals.splice(first..last, pieces);
+    }
+
+    pub fn contains_value(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(i).is_some_and(|i| i.contains_value(value))
+    }
+
+    // true if every value in the interval is in the set
+    pub fn contains(&self, interval: &Interval<T>) -> bool {
+        let i = self.intervals.partition_point(|i| i.end < interval.end);
+        interval.is_empty() || self.intervals.get(i).is_some_and(|i| i.contains(interval))
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for interval in other.iter() {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        // walk both lists together, always stepping past whichever interval ends first
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            if let Some(both) = a.intersect(&b) {
+                intervals.push(both);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = self.clone();
+        for interval in other.iter() {
+            difference.remove(*interval);
+        }
+        difference
+    }
+
+    // The values within bounds that are not in the set
+    pub fn complement(&self, bounds: Interval<T>) -> IntervalSet<T> {
+        let mut complement = IntervalSet::new();
+        complement.insert(bounds);
+        complement.difference(self)
+    }
+
+    // The number of values covered by all the intervals together
+    pub fn total_len(&self) -> T
+    where
+        T: std::ops::Sub<Output = T> + std::ops::Add<Output = T> + num::Zero,
+    {
+        self.intervals.iter().fold(T::zero(), |total, i| total + i.len())
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // test the interval functions
+    #[test]
+    fn test_interval_intersect() {
+        let a = Interval::new(1, 5);
+        let b = Interval::new(3, 7);
+        let c = Interval::new(6, 8);
+
+        assert_eq!(intersect(a, b), Some(Interval::new(3, 5)));
+        assert_eq!(intersect(a, c), None);
+    }
+
+    #[test]
+    fn test_interval_union() {
+        let a = Interval::new(1, 5);
+        let b = Interval::new(3, 7);
+        let c = Interval::new(7, 9);
+
+        assert_eq!(union(a, b), Some(Interval::new(1, 7)));
+        // disjoint intervals have no single interval as their union
+        assert_eq!(union(a, c), None);
+        // but intervals that touch do
+        assert_eq!(union(b, c), Some(Interval::new(3, 9)));
+    }
+
+    #[test]
+    fn test_interval_difference() {
+        let a = Interval::new(1, 5);
+        let b = Interval::new(3, 7);
+        let c = Interval::new(0, 2);
+
+        assert_eq!(difference(a, b), vec![Interval::new(1, 3)]);
+        assert_eq!(difference(a, c), vec![Interval::new(2, 5)]);
+        assert_eq!(difference(a, Interval::new(2, 3)), vec![Interval::new(1, 2), Interval::new(3, 5)]);
+        // nothing is left of an interval inside the one taken away
+        assert_eq!(difference(Interval::new(2, 3), a), vec![]);
+        assert_eq!(difference(a, a), vec![]);
+    }
+
+    #[test]
+    fn test_interval_contains() {
+        let a = Interval::new(1, 5);
+        let b = Interval::new(2, 4);
+        let c = Interval::new(0, 6);
+
+        assert!(contains(a, b));
+        assert!(!contains(a, c));
+        assert!(a.contains_value(1));
+        assert!(!a.contains_value(5));
+    }
+
+    #[test]
+    fn test_interval_equals() {
+        let a = Interval::new(1, 5);
+        let b = Interval::new(1, 5);
+        let c = Interval::new(2, 6);
+
+        assert!(equals(a, b));
+        assert!(!equals(a, c));
+    }
+
+    #[test]
+    fn test_interval_to_string() {
+        let a = Interval::new(1, 5);
+        assert_eq!(to_string(a), "[1, 5)");
+        assert_eq!(a.to_string(), "[1, 5)");
+    }
+
+    #[test]
+    fn test_interval_less() {
+        let a = Interval::new(1, 5);
+        let b = Interval::new(2, 6);
+        let c = Interval::new(1, 4);
+
+        assert!(less(a, b));
+        assert!(!less(a, c));
+    }
+
+    #[test]
+    fn test_interval_set_insert_merges() {
+        let mut set = IntervalSet::new();
+        set.insert(Interval::new(10, 12));
+        set.insert(Interval::new(1, 3));
+        set.insert(Interval::new(5, 7));
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![Interval::new(1, 3), Interval::new(5, 7), Interval::new(10, 12)]);
+        // touching merges too
+        set.insert(Interval::new(3, 4));
+        // bridging several intervals merges them all
+        set.insert(Interval::new(6, 11));
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![Interval::new(1, 4), Interval::new(5, 12)]);
+        set.insert(Interval::new(8, 8));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.total_len(), 10);
+        // the same values inserted in any order give the same set
+        let other: IntervalSet<i32> = [Interval::new(5, 12), Interval::new(2, 4), Interval::new(1, 3)].into_iter().collect();
+        assert_eq!(set, other);
+    }
+
+    #[test]
+    fn test_interval_set_remove() {
+        let mut set: IntervalSet<i32> = [Interval::new(0, 10), Interval::new(20, 30)].into_iter().collect();
+        set.remove(Interval::new(3, 5));
+        set.remove(Interval::new(8, 22));
+        set.remove(Interval::new(30, 40));
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![Interval::new(0, 3), Interval::new(5, 8), Interval::new(22, 30)]);
+        assert!(set.contains_value(22));
+        assert!(!set.contains_value(8));
+        assert!(set.contains(&Interval::new(23, 30)));
+        assert!(!set.contains(&Interval::new(4, 6)));
+        set.remove(Interval::new(-5, 50));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_interval_set_operations() {
+        let a: IntervalSet<i64> = [Interval::new(0, 5), Interval::new(10, 15)].into_iter().collect();
+        let b: IntervalSet<i64> = [Interval::new(3, 12), Interval::new(14, 20)].into_iter().collect();
+        let intervals = |set: IntervalSet<i64>| set.iter().copied().collect::<Vec<_>>();
+        assert_eq!(intervals(a.intersection(&b)), vec![Interval::new(3, 5), Interval::new(10, 12), Interval::new(14, 15)]);
+        assert_eq!(intervals(a.union(&b)), vec![Interval::new(0, 20)]);
+        assert_eq!(intervals(a.difference(&b)), vec![Interval::new(0, 3), Interval::new(12, 14)]);
+        assert_eq!(intervals(a.complement(Interval::new(-2, 12))), vec![Interval::new(-2, 0), Interval::new(5, 10)]);
+        assert_eq!(a.complement(Interval::new(-2, 12)).total_len(), 7);
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+type MemoFn<'a, Arg, Out> = Rc<dyn Fn(&mut Memo<'a, Arg, Out>, &Arg) -> Out + 'a>;
+
+// A recursive function that remembers its results. The function is given the Memo itself,
+// and calls memo.get for its recursive calls so those are remembered too:
+//
+//     let mut ways = Memo::new(|memo, &n: &u64| if n < 2 { 1 } else { memo.get(n - 1) + memo.get(n - 2) });
+//     let answer = ways.get(90);
+//
+// The cache lasts as long as the Memo, so reuse one Memo for every call that shares the same function.
+pub struct Memo<'a, Arg, Out> {
+    function: MemoFn<'a, Arg, Out>,
+    cache: HashMap<Arg, Out>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<'a, Arg, Out> Memo<'a, Arg, Out>
+where
+    Arg: Eq + Hash,
+    Out: Clone,
+{
+    pub fn new<Function>(function: Function) -> Self
+    where
+        Function: Fn(&mut Memo<'a, Arg, Out>, &Arg) -> Out + 'a,
+    {
+        Memo { function: Rc::new(function), cache: HashMap::new(), hits: 0, misses: 0 }
+    }
+
+    // The function's result for arg, worked out only the first time it is asked for
+    pub fn get(&mut self, arg: Arg) -> Out {
+        if let Some(out) = self.cache.get(&arg) {
+            self.hits += 1;
+            return out.clone();
+        }
+        self.misses += 1;
+        // the function needs the memo mutably, so call it through its own handle
+        let function = Rc::clone(&self.function);
+        let out = function(self, &arg);
+        self.cache.insert(arg, out.clone());
+        out
+    }
+
+    // How many calls were answered from the cache
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    // How many calls had to run the function
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    // How many results are remembered
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    // Forget every result, and reset the counts
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_memo() {
+        let calls = Cell::new(0);
+        let mut fibonacci = Memo::new(|memo, &n: &u64| {
+            calls.set(calls.get() + 1);
+            if n < 2 { n } else { memo.get(n - 1) + memo.get(n - 2) }
+        });
+        assert_eq!(fibonacci.get(90), 2880067194370816120);
+        // each value is worked out once, and the other calls are hits
+        assert_eq!(fibonacci.misses(), 91);
+        assert_eq!(fibonacci.hits(), 88);
+        assert_eq!(fibonacci.len(), 91);
+        assert_eq!(fibonacci.get(50), 12586269025);
+        assert_eq!(fibonacci.hits(), 89);
+        fibonacci.clear();
+        assert!(fibonacci.is_empty());
+        assert_eq!(fibonacci.get(10), 55);
+        assert_eq!(calls.get(), 91 + 11);
+    }
+
+    #[test]
+    fn test_memo_borrowed_keys() {
+        // the ways to make a word from pieces, keyed by what is left of the word
+        let pieces = ["a", "ab", "b"];
+        let mut ways = Memo::new(|memo, rest: &&str| -> u64 {
+            if rest.is_empty() {
+                return 1;
+            }
+            pieces.iter().filter(|piece| rest.starts_with(*piece)).map(|piece| memo.get(&rest[piece.len()..])).sum()
+        });
+        assert_eq!(ways.get("abab"), 4);
+        assert_eq!(ways.get("ba"), 1);
+        assert_eq!(ways.get("c"), 0);
+    }
+}
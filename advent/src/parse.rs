@@ -0,0 +1,231 @@
+// Helpers for turning puzzle input into typed values. Each reports what went wrong with the
+// line and column it happened at, as far as it knows them, so parsers can just use ? on them.
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{parse_value, split_input_into_numbered_sections, try_parse_lines, try_split_input_into_sections, Error};
+
+// Every integer in s, ignoring whatever is around them. A - directly in front of a number makes
+// it negative, unless it follows another digit, so "3-4" is 3 and 4.
+pub fn ints<T>(s: &str) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let bytes = s.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit) && (i == 0 || !bytes[i - 1].is_ascii_digit());
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        numbers.push(parse_value(&s[start..i]).map_err(|e| e.at_column(start + 1))?);
+    }
+    Ok(numbers)
+}
+
+// Exactly N integers from s, as found by ints
+pub fn ints_array<T, const N: usize>(s: &str) -> Result<[T; N], Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let numbers = ints(s)?;
+    let found = numbers.len();
+    numbers.try_into().map_err(|_| Error::new(format!("expected {} numbers, found {}", N, found)))
+}
+
+// Split s at the first separator, and parse the parts either side of it
+pub fn split_once_typed<A, B>(s: &str, separator: &str) -> Result<(A, B), Error>
+where
+    A: FromStr,
+    A::Err: fmt::Display,
+    B: FromStr,
+    B::Err: fmt::Display,
+{
+    let (a, b) = s.split_once(separator).ok_or_else(|| Error::expected(separator))?;
+    let b_column = a.len() + separator.len() + 1;
+    Ok((parse_value(a).map_err(|e| e.at_column(1))?, parse_value(b).map_err(|e| e.at_column(b_column))?))
+}
+
+// Parse every line of the input as a value, such as a number
+pub fn lines<T>(input: &str) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    try_parse_lines(input, parse_value)
+}
+
+// Parse an input made of two sections separated by a blank line, each with its own parser.
+// Line numbers in errors from the second parser count from the start of the whole input.
+pub fn sections<A, B, ParseA, ParseB>(input: &str, parse_first: ParseA, parse_second: ParseB) -> Result<(A, B), Error>
+where
+    ParseA: Fn(&str) -> Result<A, Error>,
+    ParseB: Fn(&str) -> Result<B, Error>,
+{
+    let sections = split_input_into_numbered_sections(input.trim_end_matches('\n'));
+    let Ok([(first_line, first), (second_line, second)]) = <[_; 2]>::try_from(sections) else {
+        return Err(try_split_input_into_sections(input, 2).unwrap_err());
+    };
+    let first = parse_first(&first).map_err(|e| e.offset_line(first_line))?;
+    let second = parse_second(&second).map_err(|e| e.offset_line(second_line))?;
+    Ok((first, second))
+}
+
+// Puzzle examples sometimes use other numbers than the puzzle itself, like a smaller space. An
+// example input can give its own in a first section, before a blank line, and without one the
+// puzzle's defaults are used. The rest of the input has to be a single section.
+pub fn with_settings<S, T, ParseS, ParseT>(input: &str, defaults: S, parse_settings: ParseS, parse_rest: ParseT) -> Result<(S, T), Error>
+where
+    ParseS: Fn(&str) -> Result<S, Error>,
+    ParseT: Fn(&str) -> Result<T, Error>,
+{
+    if split_input_into_numbered_sections(input.trim_end_matches('\n')).len() == 1 {
+        return Ok((defaults, parse_rest(input)?));
+    }
+    sections(input, parse_settings, parse_rest)
+}
+
+// Match s against a pattern where each {} stands for a value, like scanf, and parse the values:
+//
+//     let (x, y): (i32, i32) = scan("Button A: X+{}, Y+{}", line)?;
+//
+// Each value runs up to the first place the text after its {} appears, or the last place for
+// the last value, and there can't be anything after the end of the pattern. A pattern can't
+// have two {} next to each other, as there would be no way to tell where the first one stops.
+pub fn scan<T: Scan>(pattern: &str, s: &str) -> Result<T, Error> {
+    let values = scan_values(pattern, s)?;
+    if values.len() != T::COUNT {
+        panic!("`{}` has {} values but {} were asked for", pattern, values.len(), T::COUNT);
+    }
+    T::from_values(&values)
+}
+
+// The text of each value matched by a scan pattern, with the column it starts at
+fn scan_values<'a>(pattern: &str, s: &'a str) -> Result<Vec<(usize, &'a str)>, Error> {
+    let expected = || Error::expected(pattern);
+    let mut literals = pattern.split("{}");
+    let first = literals.next().unwrap_or_default();
+    let mut rest = s.strip_prefix(first).ok_or_else(|| expected().at_column(1))?;
+    let mut values = Vec::new();
+    let mut literals = literals.peekable();
+    while let Some(literal) = literals.next() {
+        let column = s.len() - rest.len() + 1;
+        let end = if literals.peek().is_none() {
+            // the last value takes everything up to the text that ends the pattern
+            rest.rfind(literal)
+        } else {
+            assert!(!literal.is_empty(), "`{}` has two {{}} next to each other", pattern);
+            rest.find(literal)
+        };
+        let end = end.ok_or_else(|| expected().at_column(column))?;
+        let value = &rest[..end];
+        rest = &rest[end + literal.len()..];
+        values.push((column, value));
+    }
+    if !rest.is_empty() {
+        return Err(expected().at_column(s.len() - rest.len() + 1));
+    }
+    Ok(values)
+}
+
+// The values a scan pattern can be parsed into: a tuple with one type for each {}
+pub trait Scan: Sized {
+    const COUNT: usize;
+
+    fn from_values(values: &[(usize, &str)]) -> Result<Self, Error>;
+}
+
+macro_rules! impl_scan {
+    ($count:expr; $($t:ident $i:tt),+) => {
+        impl<$($t),+> Scan for ($($t,)+)
+        where
+            $($t: FromStr, $t::Err: fmt::Display,)+
+        {
+            const COUNT: usize = $count;
+
+            fn from_values(values: &[(usize, &str)]) -> Result<Self, Error> {
+                Ok(($(parse_value::<$t>(values[$i].1).map_err(|e| e.at_column(values[$i].0))?,)+))
+            }
+        }
+    };
+}
+
+impl_scan!(1; A 0);
+impl_scan!(2; A 0, B 1);
+impl_scan!(3; A 0, B 1, C 2);
+impl_scan!(4; A 0, B 1, C 2, D 3);
+impl_scan!(5; A 0, B 1, C 2, D 3, E 4);
+impl_scan!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i64>("p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(ints::<i32>("190: 10 19"), Ok(vec![190, 10, 19]));
+        assert_eq!(ints::<i32>("3-4 -5 - 6 x-7"), Ok(vec![3, 4, -5, 6, -7]));
+        assert_eq!(ints::<u8>("no numbers"), Ok(vec![]));
+        let error = ints::<u8>("1, 2, 300").unwrap_err();
+        assert_eq!(error.to_string(), "column 7: can't parse `300`: number too large to fit in target type");
+        assert_eq!(ints_array::<i32, 2>("3   4"), Ok([3, 4]));
+        assert_eq!(ints_array::<i32, 2>("3 4 5").unwrap_err().message, "expected 2 numbers, found 3");
+    }
+
+    #[test]
+    fn test_split_once_typed() {
+        assert_eq!(split_once_typed::<i32, i32>("47|53", "|"), Ok((47, 53)));
+        assert_eq!(split_once_typed::<String, u8>("x00: 1", ": "), Ok(("x00".to_string(), 1)));
+        assert_eq!(split_once_typed::<i32, i32>("47,53", "|").unwrap_err().message, "expected `|`");
+        assert_eq!(split_once_typed::<i32, i32>("47|5x", "|").unwrap_err().column, Some(4));
+    }
+
+    #[test]
+    fn test_lines_and_sections() {
+        assert_eq!(lines::<u64>("1\n10\n100\n"), Ok(vec![1, 10, 100]));
+        assert_eq!(lines::<u64>("1\nten").unwrap_err().line, Some(2));
+        let parse = |input: &str| sections(input, lines::<u32>, |s| Ok(s.to_string()));
+        assert_eq!(parse("1\n2\n\nabc\n"), Ok((vec![1, 2], "abc".to_string())));
+        let error = parse("1\n2\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: expected 2 sections separated by blank lines, found 1");
+        // lines in the second section count from the start of the input
+        let error = sections("1\n\n2\nx", lines::<u32>, lines::<u32>).unwrap_err();
+        assert_eq!(error.line, Some(4));
+    }
+
+    #[test]
+    fn test_with_settings() {
+        let parse = |input: &str| with_settings(input, [101, 103], ints_array::<i32, 2>, lines::<u32>);
+        assert_eq!(parse("1\n2\n"), Ok(([101, 103], vec![1, 2])));
+        assert_eq!(parse("11,7\n\n1\n2\n"), Ok(([11, 7], vec![1, 2])));
+        assert_eq!(parse("11,7\n\n1\nx\n").unwrap_err().line, Some(4));
+        assert!(parse("1\n\n2\n\n3").is_err());
+    }
+
+    #[test]
+    fn test_scan() {
+        let robot: (i32, i32, i32, i32) = scan("p={},{} v={},{}", "p=6,3 v=-1,-3").unwrap();
+        assert_eq!(robot, (6, 3, -1, -3));
+        let gate: (String, String, String, String) = scan("{} {} {} -> {}", "x00 AND y00 -> z00").unwrap();
+        assert_eq!(gate, ("x00".into(), "AND".into(), "y00".into(), "z00".into()));
+        assert_eq!(scan::<(u32,)>("Register A: {}", "Register A: 729"), Ok((729,)));
+        let error = scan::<(i32, i32)>("Button A: X+{}, Y+{}", "Button B: X+94, Y+34").unwrap_err();
+        assert_eq!(error.to_string(), "column 1: expected `Button A: X+{}, Y+{}`");
+        let error = scan::<(i32, i32)>("X+{}, Y+{}", "X+94; Y+34").unwrap_err();
+        assert_eq!(error.column, Some(3));
+        let error = scan::<(i32, i32)>("X+{}, Y+{}", "X+94, Y+3x").unwrap_err();
+        assert_eq!(error.to_string(), "column 9: can't parse `3x`: invalid digit found in string");
+        // text after the pattern is an error too
+        assert_eq!(scan::<(i32,)>("{}!", "1!?").unwrap_err().column, Some(3));
+    }
+}
@@ -0,0 +1,726 @@
+use num::traits::{Euclid, Signed};
+
+// define generic points and vectors in 2D space
+// Points order by x and then y, so they can be sorted and used as keys in ordered maps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2D<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2D<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn add(&self, other: &Point2D<T>) -> Point2D<T>
+    where
+        T: std::ops::Add<Output = T> + Copy,
+    {
+        Point2D::new(self.x + other.x, self.y + other.y)
+    }
+
+    pub fn subtract(&self, other: &Point2D<T>) -> Point2D<T>
+    where
+        T: std::ops::Sub<Output = T> + Copy,
+    {
+        Point2D::new(self.x - other.x, self.y - other.y)
+    }
+
+    pub fn scale(&self, scalar: T) -> Point2D<T>
+    where
+        T: std::ops::Mul<Output = T> + Copy,
+    {
+        Point2D::new(self.x * scalar, self.y * scalar)
+    }
+
+    pub fn manhattan_distance(&self, other: &Point2D<T>) -> T
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + Copy + Signed,
+    {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // The number of steps between the points when diagonal steps are allowed
+    pub fn chebyshev_distance(&self, other: &Point2D<T>) -> T
+    where
+        T: std::ops::Sub<Output = T> + Copy + Signed + Ord,
+    {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    pub fn euclidean_distance(&self, other: &Point2D<T>) -> f64
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + Copy,
+        f64: std::convert::From<T>,
+    {
+        let dx = f64::from(self.x - other.x);
+        let dy = f64::from(self.y - other.y);
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    pub fn dot(&self, other: &Point2D<T>) -> T
+    where
+        T: std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy,
+    {
+        self.x * other.x + self.y * other.y
+    }
+
+    // The z of the 3D cross product, positive when other is clockwise of self on a grid with y down
+    pub fn cross(&self, other: &Point2D<T>) -> T
+    where
+        T: std::ops::Sub<Output = T> + std::ops::Mul<Output = T> + Copy,
+    {
+        self.x * other.y - self.y * other.x
+    }
+
+    // A quarter turn clockwise as drawn on a grid with y down, so (1,0) east becomes (0,1) south
+    pub fn rotate_right(&self) -> Point2D<T>
+    where
+        T: std::ops::Neg<Output = T> + Copy,
+    {
+        Point2D::new(-self.y, self.x)
+    }
+
+    // A quarter turn anticlockwise as drawn on a grid with y down, so (1,0) east becomes (0,-1) north
+    pub fn rotate_left(&self) -> Point2D<T>
+    where
+        T: std::ops::Neg<Output = T> + Copy,
+    {
+        Point2D::new(self.y, -self.x)
+    }
+
+    // Wrap the point into the space from (0,0) up to but not including size, as if its edges were joined
+    pub fn rem_euclid(&self, size: &Point2D<T>) -> Point2D<T>
+    where
+        T: Euclid,
+    {
+        Point2D::new(self.x.rem_euclid(&size.x), self.y.rem_euclid(&size.y))
+    }
+
+    // The 8 points around this one, including diagonals
+    pub fn neighbors(&self) -> Vec<Point2D<T>>
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + num::One + Copy,
+    {
+        PointN::new([self.x, self.y]).neighbors().into_iter().map(|p| p.into()).collect()
+    }
+
+    // The 4 points next to this one, not including diagonals
+    pub fn orthogonal_neighbors(&self) -> Vec<Point2D<T>>
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + num::One + Copy,
+    {
+        PointN::new([self.x, self.y]).orthogonal_neighbors().into_iter().map(|p| p.into()).collect()
+    }
+}
+
+impl<T: std::ops::Add<Output = T>> std::ops::Add for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn add(self, other: Point2D<T>) -> Point2D<T> {
+        Point2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: std::ops::Sub<Output = T>> std::ops::Sub for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn sub(self, other: Point2D<T>) -> Point2D<T> {
+        Point2D::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: std::ops::Neg<Output = T>> std::ops::Neg for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn neg(self) -> Point2D<T> {
+        Point2D::new(-self.x, -self.y)
+    }
+}
+
+// scale by a scalar
+impl<T: std::ops::Mul<Output = T> + Copy> std::ops::Mul<T> for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn mul(self, scalar: T) -> Point2D<T> {
+        Point2D::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T: std::ops::AddAssign> std::ops::AddAssign for Point2D<T> {
+    fn add_assign(&mut self, other: Point2D<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: std::ops::SubAssign> std::ops::SubAssign for Point2D<T> {
+    fn sub_assign(&mut self, other: Point2D<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point2D<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2D::new(x, y)
+    }
+}
+
+impl<T> From<Point2D<T>> for (T, T) {
+    fn from(p: Point2D<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+// define generic points and vectors in 3D space, with the same API as Point2D
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3D<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3D<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn add(&self, other: &Point3D<T>) -> Point3D<T>
+    where
+        T: std::ops::Add<Output = T> + Copy,
+    {
+        Point3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+
+    pub fn subtract(&self, other: &Point3D<T>) -> Point3D<T>
+    where
+        T: std::ops::Sub<Output = T> + Copy,
+    {
+        Point3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+
+    pub fn scale(&self, scalar: T) -> Point3D<T>
+    where
+        T: std::ops::Mul<Output = T> + Copy,
+    {
+        Point3D::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+
+    pub fn manhattan_distance(&self, other: &Point3D<T>) -> T
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + Copy + Signed,
+    {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    // The number of steps between the points when diagonal steps are allowed
+    pub fn chebyshev_distance(&self, other: &Point3D<T>) -> T
+    where
+        T: std::ops::Sub<Output = T> + Copy + Signed + Ord,
+    {
+        (self.x - other.x).abs().max((self.y - other.y).abs()).max((self.z - other.z).abs())
+    }
+
+    pub fn euclidean_distance(&self, other: &Point3D<T>) -> f64
+    where
+        T: std::ops::Sub<Output = T> + Copy,
+        f64: std::convert::From<T>,
+    {
+        let dx = f64::from(self.x - other.x);
+        let dy = f64::from(self.y - other.y);
+        let dz = f64::from(self.z - other.z);
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    pub fn dot(&self, other: &Point3D<T>) -> T
+    where
+        T: std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy,
+    {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Point3D<T>) -> Point3D<T>
+    where
+        T: std::ops::Sub<Output = T> + std::ops::Mul<Output = T> + Copy,
+    {
+        Point3D::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    // Wrap the point into the space from (0,0,0) up to but not including size, as if its edges were joined
+    pub fn rem_euclid(&self, size: &Point3D<T>) -> Point3D<T>
+    where
+        T: Euclid,
+    {
+        Point3D::new(self.x.rem_euclid(&size.x), self.y.rem_euclid(&size.y), self.z.rem_euclid(&size.z))
+    }
+
+    // The 26 points around this one, including diagonals
+    pub fn neighbors(&self) -> Vec<Point3D<T>>
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + num::One + Copy,
+    {
+        PointN::new([self.x, self.y, self.z]).neighbors().into_iter().map(|p| p.into()).collect()
+    }
+
+    // The 6 points next to this one, sharing a face with it
+    pub fn orthogonal_neighbors(&self) -> Vec<Point3D<T>>
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + num::One + Copy,
+    {
+        PointN::new([self.x, self.y, self.z]).orthogonal_neighbors().into_iter().map(|p| p.into()).collect()
+    }
+}
+
+impl<T: std::ops::Add<Output = T>> std::ops::Add for Point3D<T> {
+    type Output = Point3D<T>;
+
+    fn add(self, other: Point3D<T>) -> Point3D<T> {
+        Point3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: std::ops::Sub<Output = T>> std::ops::Sub for Point3D<T> {
+    type Output = Point3D<T>;
+
+    fn sub(self, other: Point3D<T>) -> Point3D<T> {
+        Point3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: std::ops::Neg<Output = T>> std::ops::Neg for Point3D<T> {
+    type Output = Point3D<T>;
+
+    fn neg(self) -> Point3D<T> {
+        Point3D::new(-self.x, -self.y, -self.z)
+    }
+}
+
+// scale by a scalar
+impl<T: std::ops::Mul<Output = T> + Copy> std::ops::Mul<T> for Point3D<T> {
+    type Output = Point3D<T>;
+
+    fn mul(self, scalar: T) -> Point3D<T> {
+        Point3D::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl<T: std::ops::AddAssign> std::ops::AddAssign for Point3D<T> {
+    fn add_assign(&mut self, other: Point3D<T>) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<T: std::ops::SubAssign> std::ops::SubAssign for Point3D<T> {
+    fn sub_assign(&mut self, other: Point3D<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3D<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3D::new(x, y, z)
+    }
+}
+
+impl<T> From<Point3D<T>> for (T, T, T) {
+    fn from(p: Point3D<T>) -> Self {
+        (p.x, p.y, p.z)
+    }
+}
+
+// define generic points and vectors in any number of dimensions, with the same API as Point2D and Point3D.
+// Four dimensional puzzles are PointN<i32, 4>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PointN<T, const N: usize> {
+    pub coords: [T; N],
+}
+
+impl<T, const N: usize> PointN<T, N> {
+    pub fn new(coords: [T; N]) -> Self {
+        Self { coords }
+    }
+
+    // combine the coordinates of two points pairwise
+    fn zip_with<F>(&self, other: &PointN<T, N>, f: F) -> PointN<T, N>
+    where
+        T: Copy,
+        F: Fn(T, T) -> T,
+    {
+        PointN::new(std::array::from_fn(|i| f(self.coords[i], other.coords[i])))
+    }
+
+    pub fn add(&self, other: &PointN<T, N>) -> PointN<T, N>
+    where
+        T: std::ops::Add<Output = T> + Copy,
+    {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    pub fn subtract(&self, other: &PointN<T, N>) -> PointN<T, N>
+    where
+        T: std::ops::Sub<Output = T> + Copy,
+    {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    pub fn scale(&self, scalar: T) -> PointN<T, N>
+    where
+        T: std::ops::Mul<Output = T> + Copy,
+    {
+        PointN::new(self.coords.map(|a| a * scalar))
+    }
+
+    pub fn manhattan_distance(&self, other: &PointN<T, N>) -> T
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + Copy + Signed,
+    {
+        self.subtract(other).coords.iter().fold(T::zero(), |sum, d| sum + d.abs())
+    }
+
+    // The number of steps between the points when diagonal steps are allowed
+    pub fn chebyshev_distance(&self, other: &PointN<T, N>) -> T
+    where
+        T: std::ops::Sub<Output = T> + Copy + Signed + Ord,
+    {
+        self.subtract(other).coords.iter().fold(T::zero(), |max, d| max.max(d.abs()))
+    }
+
+    pub fn euclidean_distance(&self, other: &PointN<T, N>) -> f64
+    where
+        T: std::ops::Sub<Output = T> + Copy,
+        f64: std::convert::From<T>,
+    {
+        self.subtract(other).coords.iter().map(|&d| f64::from(d) * f64::from(d)).sum::<f64>().sqrt()
+    }
+
+    pub fn dot(&self, other: &PointN<T, N>) -> T
+    where
+        T: std::ops::Add<Output = T> + std::ops::Mul<Output = T> + num::Zero + Copy,
+    {
+        (0..N).fold(T::zero(), |sum, i| sum + self.coords[i] * other.coords[i])
+    }
+
+    // Wrap the point into the space from the origin up to but not including size, as if its edges were joined
+    pub fn rem_euclid(&self, size: &PointN<T, N>) -> PointN<T, N>
+    where
+        T: Euclid + Copy,
+    {
+        self.zip_with(size, |a, b| a.rem_euclid(&b))
+    }
+
+    // The 3^N-1 points around this one, including diagonals.
+    // Each neighbor moves -1, 0 or +1 along every axis, and only the point itself moves 0 along all of them.
+    pub fn neighbors(&self) -> Vec<PointN<T, N>>
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + num::One + Copy,
+    {
+        let count = 3usize.pow(N as u32);
+        // the point itself is the middle of the 3^N cube
+        let center = count / 2;
+        (0..count)
+            .filter(|&i| i != center)
+            .map(|i| {
+                // read i as N base 3 digits, one step (0 = -1, 1 = 0, 2 = +1) per axis
+                PointN::new(std::array::from_fn(|axis| {
+                    let c = self.coords[axis];
+                    match (i / 3usize.pow(axis as u32)) % 3 {
+                        0 => c - T::one(),
+                        1 => c,
+                        _ => c + T::one(),
+                    }
+                }))
+            })
+            .collect()
+    }
+
+    // The 2N points next to this one, one step along a single axis
+    pub fn orthogonal_neighbors(&self) -> Vec<PointN<T, N>>
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + num::One + Copy,
+    {
+        let mut neighbors = Vec::with_capacity(2 * N);
+        for axis in 0..N {
+            let mut lower = *self;
+            lower.coords[axis] = self.coords[axis] - T::one();
+            let mut upper = *self;
+            upper.coords[axis] = self.coords[axis] + T::one();
+            neighbors.push(lower);
+            neighbors.push(upper);
+        }
+        neighbors
+    }
+}
+
+impl<T: std::ops::Add<Output = T> + Copy, const N: usize> std::ops::Add for PointN<T, N> {
+    type Output = PointN<T, N>;
+
+    fn add(self, other: PointN<T, N>) -> PointN<T, N> {
+        self.zip_with(&other, |a, b| a + b)
+    }
+}
+
+impl<T: std::ops::Sub<Output = T> + Copy, const N: usize> std::ops::Sub for PointN<T, N> {
+    type Output = PointN<T, N>;
+
+    fn sub(self, other: PointN<T, N>) -> PointN<T, N> {
+        self.zip_with(&other, |a, b| a - b)
+    }
+}
+
+impl<T: std::ops::Neg<Output = T>, const N: usize> std::ops::Neg for PointN<T, N> {
+    type Output = PointN<T, N>;
+
+    fn neg(self) -> PointN<T, N> {
+        PointN::new(self.coords.map(|a| -a))
+    }
+}
+
+// scale by a scalar
+impl<T: std::ops::Mul<Output = T> + Copy, const N: usize> std::ops::Mul<T> for PointN<T, N> {
+    type Output = PointN<T, N>;
+
+    fn mul(self, scalar: T) -> PointN<T, N> {
+        PointN::new(self.coords.map(|a| a * scalar))
+    }
+}
+
+impl<T: std::ops::AddAssign + Copy, const N: usize> std::ops::AddAssign for PointN<T, N> {
+    fn add_assign(&mut self, other: PointN<T, N>) {
+        for (a, b) in self.coords.iter_mut().zip(other.coords) {
+            *a += b;
+        }
+    }
+}
+
+impl<T: std::ops::SubAssign + Copy, const N: usize> std::ops::SubAssign for PointN<T, N> {
+    fn sub_assign(&mut self, other: PointN<T, N>) {
+        for (a, b) in self.coords.iter_mut().zip(other.coords) {
+            *a -= b;
+        }
+    }
+}
+
+impl<T, const N: usize> std::ops::Index<usize> for PointN<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        &self.coords[axis]
+    }
+}
+
+impl<T, const N: usize> std::ops::IndexMut<usize> for PointN<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.coords[axis]
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for PointN<T, N> {
+    fn from(coords: [T; N]) -> Self {
+        PointN::new(coords)
+    }
+}
+
+impl<T> From<Point2D<T>> for PointN<T, 2> {
+    fn from(p: Point2D<T>) -> Self {
+        PointN::new([p.x, p.y])
+    }
+}
+
+impl<T: Copy> From<PointN<T, 2>> for Point2D<T> {
+    fn from(p: PointN<T, 2>) -> Self {
+        Point2D::new(p.coords[0], p.coords[1])
+    }
+}
+
+impl<T> From<Point3D<T>> for PointN<T, 3> {
+    fn from(p: Point3D<T>) -> Self {
+        PointN::new([p.x, p.y, p.z])
+    }
+}
+
+impl<T: Copy> From<PointN<T, 3>> for Point3D<T> {
+    fn from(p: PointN<T, 3>) -> Self {
+        Point3D::new(p.coords[0], p.coords[1], p.coords[2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // add tests for the Point2D struct
+    #[test]
+    fn test_point2d_add() {
+        let a = Point2D::new(1, 2);
+        let b = Point2D::new(3, 4);
+        let c = a.add(&b);
+        assert_eq!(c, Point2D::new(4, 6));
+    }
+
+    #[test]
+    fn test_point2d_subtract() {
+        let a = Point2D::new(1, 2);
+        let b = Point2D::new(3, 4);
+        let c = a.subtract(&b);
+        assert_eq!(c, Point2D::new(-2, -2));
+    }
+
+    #[test]
+    fn test_point2d_manhattan_distance() {
+        let a = Point2D::new(1, 2);
+        let b = Point2D::new(3, 4);
+        let c = a.manhattan_distance(&b);
+        assert_eq!(c, 4);
+    }
+
+    #[test]
+    fn test_point2d_euclidean_distance() {
+        let a = Point2D::new(1, 2);
+        let b = Point2D::new(3, 4);
+        let c = a.euclidean_distance(&b);
+        assert_eq!(c, 2.8284271247461903);
+    }
+
+    #[test]
+    fn test_point2d_operators() {
+        let a = Point2D::new(1, 2);
+        let b = Point2D::new(3, -4);
+        assert_eq!(a + b, Point2D::new(4, -2));
+        assert_eq!(a - b, Point2D::new(-2, 6));
+        assert_eq!(-a, Point2D::new(-1, -2));
+        assert_eq!(b * 3, Point2D::new(9, -12));
+        let mut c = a;
+        c += b;
+        assert_eq!(c, Point2D::new(4, -2));
+        c -= b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn test_point2d_products_and_rotation() {
+        let east = Point2D::new(1, 0);
+        let south = Point2D::new(0, 1);
+        assert_eq!(east.dot(&south), 0);
+        assert_eq!(Point2D::new(2, 3).dot(&Point2D::new(4, 5)), 23);
+        assert_eq!(east.cross(&south), 1);
+        assert_eq!(south.cross(&east), -1);
+        assert_eq!(east.rotate_right(), south);
+        assert_eq!(east.rotate_left(), Point2D::new(0, -1));
+        let p = Point2D::new(3, -7);
+        assert_eq!(p.rotate_right().rotate_right().rotate_right().rotate_right(), p);
+        assert_eq!(p.rotate_left().rotate_right(), p);
+    }
+
+    #[test]
+    fn test_point2d_chebyshev_distance() {
+        assert_eq!(Point2D::new(1, 2).chebyshev_distance(&Point2D::new(4, -3)), 5);
+        assert_eq!(Point2D::new(0, 0).chebyshev_distance(&Point2D::new(-2, 2)), 2);
+    }
+
+    #[test]
+    fn test_point2d_rem_euclid() {
+        let size = Point2D::new(11, 7);
+        assert_eq!(Point2D::new(12, -1).rem_euclid(&size), Point2D::new(1, 6));
+        assert_eq!(Point2D::new(-23, 14).rem_euclid(&size), Point2D::new(10, 0));
+        assert_eq!(Point2D::new(5, 5).rem_euclid(&size), Point2D::new(5, 5));
+    }
+
+    #[test]
+    fn test_point2d_hash_ord_and_tuples() {
+        let mut points = vec![Point2D::new(2, 1), Point2D::new(1, 5), Point2D::new(1, 2)];
+        points.sort();
+        assert_eq!(points, vec![Point2D::new(1, 2), Point2D::new(1, 5), Point2D::new(2, 1)]);
+        let set: std::collections::HashSet<Point2D<i32>> = points.iter().copied().collect();
+        assert!(set.contains(&Point2D::from((1, 5))));
+        let (x, y): (i32, i32) = Point2D::new(7, 8).into();
+        assert_eq!((x, y), (7, 8));
+    }
+
+    #[test]
+    fn test_point2d_neighbors() {
+        let p = Point2D::new(5, 5);
+        let neighbors = p.neighbors();
+        assert_eq!(neighbors.len(), 8);
+        assert!(!neighbors.contains(&p));
+        assert!(neighbors.iter().all(|n| n.chebyshev_distance(&p) == 1));
+        let orthogonal = p.orthogonal_neighbors();
+        assert_eq!(orthogonal.len(), 4);
+        assert!(orthogonal.iter().all(|n| n.manhattan_distance(&p) == 1));
+    }
+
+    #[test]
+    fn test_point3d() {
+        let a = Point3D::new(1, 2, 3);
+        let b = Point3D::new(-4, 5, 0);
+        assert_eq!(a + b, Point3D::new(-3, 7, 3));
+        assert_eq!(a - b, a.subtract(&b));
+        assert_eq!(-a * 2, Point3D::new(-2, -4, -6));
+        assert_eq!(a.manhattan_distance(&b), 11);
+        assert_eq!(a.chebyshev_distance(&b), 5);
+        assert_eq!(a.dot(&b), 6);
+        assert_eq!(Point3D::new(1, 0, 0).cross(&Point3D::new(0, 1, 0)), Point3D::new(0, 0, 1));
+        assert_eq!(Point3D::new(0, 3, 4).euclidean_distance(&Point3D::new(0, 0, 0)), 5.0);
+        assert_eq!(Point3D::new(-1, 7, 2).rem_euclid(&Point3D::new(5, 5, 5)), Point3D::new(4, 2, 2));
+        assert_eq!(Point3D::from((1, 2, 3)), a);
+    }
+
+    #[test]
+    fn test_point3d_neighbors() {
+        let p = Point3D::new(0, 0, 0);
+        let neighbors: std::collections::HashSet<_> = p.neighbors().into_iter().collect();
+        assert_eq!(neighbors.len(), 26);
+        assert!(!neighbors.contains(&p));
+        assert_eq!(p.orthogonal_neighbors().len(), 6);
+        assert!(p.orthogonal_neighbors().contains(&Point3D::new(0, 0, -1)));
+    }
+
+    #[test]
+    fn test_point_n() {
+        let a = PointN::new([1, 2, 3, 4]);
+        let b = PointN::new([0, -2, 3, 1]);
+        assert_eq!(a + b, PointN::new([1, 0, 6, 5]));
+        assert_eq!(a - b, PointN::new([1, 4, 0, 3]));
+        assert_eq!(-b * 2, PointN::new([0, 4, -6, -2]));
+        assert_eq!(a.manhattan_distance(&b), 8);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.dot(&b), 9);
+        assert_eq!(a[3], 4);
+        let mut c = a;
+        c += b;
+        c -= b;
+        assert_eq!(c, a);
+        assert_eq!(PointN::from([-1, 9]).rem_euclid(&PointN::new([4, 4])), PointN::new([3, 1]));
+        let p: Point2D<i32> = PointN::new([1, 2]).into();
+        assert_eq!(p, Point2D::new(1, 2));
+    }
+
+    #[test]
+    fn test_point_n_neighbors() {
+        for (neighbors, expected) in [
+            (PointN::new([0]).neighbors().len(), 2),
+            (PointN::new([0, 0]).neighbors().len(), 8),
+            (PointN::new([0, 0, 0]).neighbors().len(), 26),
+            (PointN::new([0, 0, 0, 0]).neighbors().len(), 80),
+        ] {
+            assert_eq!(neighbors, expected);
+        }
+        let p = PointN::new([3, 3, 3, 3]);
+        let neighbors: std::collections::HashSet<_> = p.neighbors().into_iter().collect();
+        assert_eq!(neighbors.len(), 80);
+        assert!(neighbors.iter().all(|n| n.chebyshev_distance(&p) == 1));
+        assert_eq!(p.orthogonal_neighbors().len(), 8);
+        assert!(p.orthogonal_neighbors().iter().all(|n| n.manhattan_distance(&p) == 1));
+        // unsigned coordinates work too, as long as nothing goes below zero
+        assert_eq!(PointN::new([1u8, 1u8]).neighbors().len(), 8);
+    }
+}
@@ -0,0 +1,402 @@
+// Pictures of grids as image files: a PNG of one grid, or an animated GIF of a grid changing,
+// with each character drawn as a square of its color from a palette. Everything is written by
+// plain Rust, so this works anywhere, with no terminal or display needed.
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::{Error, Grid, Point2D, Visualizer};
+
+// A color as red, green and blue
+pub type Rgb = [u8; 3];
+
+// The most colors a GIF can have
+const MAX_COLORS: usize = 256;
+
+// The color to draw each character in, and one for any character not given a color
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    default: Rgb,
+    colors: Vec<(char, Rgb)>,
+}
+
+impl Palette {
+    pub fn new(default: Rgb) -> Self {
+        Palette { default, colors: Vec::new() }
+    }
+
+    // The palette with ch drawn in color, replacing any color ch had before
+    pub fn with(mut self, ch: char, color: Rgb) -> Self {
+        match self.colors.iter_mut().find(|(c, _)| *c == ch) {
+            Some(entry) => entry.1 = color,
+            None => self.colors.push((ch, color)),
+        }
+        self
+    }
+
+    pub fn color(&self, ch: char) -> Rgb {
+        self.colors.iter().find(|(c, _)| *c == ch).map_or(self.default, |(_, color)| *color)
+    }
+
+    // Where ch's color is in the color table, which starts with the default
+    fn index(&self, ch: char) -> u8 {
+        self.colors.iter().position(|(c, _)| *c == ch).map_or(0, |i| i as u8 + 1)
+    }
+
+    // Every color, one after another as red, green, blue, for a GIF's color table
+    fn table(&self) -> Result<Vec<u8>, Error> {
+        if self.colors.len() + 1 > MAX_COLORS {
+            return Err(Error::new(format!("a palette can have at most {} colors", MAX_COLORS)));
+        }
+        Ok(std::iter::once(&self.default).chain(self.colors.iter().map(|(_, color)| color)).flatten().copied().collect())
+    }
+}
+
+// The colors the visualizers use: walls and robots in light gray on black, visited cells in red,
+// paths in green, and the start and end in yellow
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::new([192, 192, 192])
+            .with('.', [16, 16, 16])
+            .with('#', [192, 192, 192])
+            .with('•', [220, 50, 47])
+            .with('■', [80, 200, 80])
+            .with('S', [240, 200, 40])
+            .with('E', [240, 200, 40])
+    }
+}
+
+// One value per pixel for the cells from top_left to just before bottom_right, each cell
+// drawn as a scale by scale square
+fn pixels<T, PixelFn>(grid: &Grid<char>, top_left: Point2D<i32>, bottom_right: Point2D<i32>, scale: u32, pixel: PixelFn) -> Vec<T>
+where
+    T: Copy,
+    PixelFn: Fn(char) -> T,
+{
+    let mut pixels = Vec::new();
+    for y in top_left.y..bottom_right.y {
+        let mut row = Vec::new();
+        for x in top_left.x..bottom_right.x {
+            let value = pixel(grid[Point2D::new(x, y)]);
+            row.extend(std::iter::repeat_n(value, scale as usize));
+        }
+        for _ in 0..scale {
+            pixels.extend_from_slice(&row);
+        }
+    }
+    pixels
+}
+
+fn image_size(grid: &Grid<char>, scale: u32) -> Result<(u32, u32), Error> {
+    if scale == 0 {
+        return Err(Error::new("the scale must be at least 1"));
+    }
+    let size = |cells: i32| (cells as u32).checked_mul(scale).ok_or_else(|| Error::new("the picture is too big"));
+    Ok((size(grid.width())?, size(grid.height())?))
+}
+
+// Write a PNG of the grid, each cell scale pixels across
+pub fn write_png<W: Write>(out: W, grid: &Grid<char>, palette: &Palette, scale: u32) -> Result<(), Error> {
+    let (width, height) = image_size(grid, scale)?;
+    let mut encoder = png::Encoder::new(out, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let data: Vec<u8> = pixels(grid, Point2D::new(0, 0), Point2D::new(grid.width(), grid.height()), scale, |ch| palette.color(ch)).concat();
+    let mut writer = encoder.write_header().map_err(png_error)?;
+    writer.write_image_data(&data).map_err(png_error)?;
+    writer.finish().map_err(png_error)
+}
+
+pub fn save_png(path: impl AsRef<Path>, grid: &Grid<char>, palette: &Palette, scale: u32) -> Result<(), Error> {
+    write_png(BufWriter::new(File::create(path)?), grid, palette, scale)
+}
+
+fn png_error(e: png::EncodingError) -> Error {
+    Error::new(format!("can't write PNG: {}", e))
+}
+
+fn gif_error(e: gif::EncodingError) -> Error {
+    Error::new(format!("can't write GIF: {}", e))
+}
+
+// An animated GIF of a grid, written a frame at a time. After the first frame, each frame holds
+// only the rectangle around the cells that changed, so an animation of a few cells changing at a
+// time stays small however many frames it has. The GIF is finished when the animation is dropped.
+pub struct Animation<W: Write> {
+    encoder: gif::Encoder<W>,
+    palette: Palette,
+    scale: u32,
+    width: i32,
+    height: i32,
+    // the last frame, which the next one is drawn over
+    shown: Option<Grid<char>>,
+}
+
+impl Animation<BufWriter<File>> {
+    pub fn create(path: impl AsRef<Path>, width: i32, height: i32, palette: Palette, scale: u32) -> Result<Self, Error> {
+        Animation::new(BufWriter::new(File::create(path)?), width, height, palette, scale)
+    }
+}
+
+impl<W: Write> Animation<W> {
+    // An animation of width by height grids, which loops forever
+    pub fn new(out: W, width: i32, height: i32, palette: Palette, scale: u32) -> Result<Self, Error> {
+        let (pixel_width, pixel_height) = image_size(&Grid::new(width, height, ' '), scale)?;
+        let too_big = || Error::new(format!("a GIF can't be {}x{} pixels", pixel_width, pixel_height));
+        let pixel_width = u16::try_from(pixel_width).map_err(|_| too_big())?;
+        let pixel_height = u16::try_from(pixel_height).map_err(|_| too_big())?;
+        let mut encoder = gif::Encoder::new(out, pixel_width, pixel_height, &palette.table()?).map_err(gif_error)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(gif_error)?;
+        Ok(Animation { encoder, palette, scale, width, height, shown: None })
+    }
+
+    // Show the grid for the delay, which GIFs count in hundredths of a second. A frame with
+    // nothing changed just makes the last one stay up for longer.
+    pub fn frame(&mut self, grid: &Grid<char>, delay: Duration) -> Result<(), Error> {
+        if grid.width() != self.width || grid.height() != self.height {
+            return Err(Error::new(format!(
+                "a {}x{} grid can't be a frame of a {}x{} animation",
+                grid.width(),
+                grid.height(),
+                self.width,
+                self.height
+            )));
+        }
+        let changed: Vec<Point2D<i32>> = match &self.shown {
+            Some(shown) => grid.iter().filter(|&(p, ch)| shown[p] != *ch).map(|(p, _)| p).collect(),
+            None => grid.points().collect(),
+        };
+        // an unchanged frame still needs a pixel to carry its delay
+        let mut top_left = Point2D::new(0, 0);
+        let mut bottom_right = Point2D::new(1, 1);
+        if let Some(&first) = changed.first() {
+            top_left = first;
+            bottom_right = first;
+            for p in changed {
+                top_left = Point2D::new(top_left.x.min(p.x), top_left.y.min(p.y));
+                bottom_right = Point2D::new(bottom_right.x.max(p.x), bottom_right.y.max(p.y));
+            }
+            bottom_right = Point2D::new(bottom_right.x + 1, bottom_right.y + 1);
+        }
+        let frame = gif::Frame {
+            left: (top_left.x as u32 * self.scale) as u16,
+            top: (top_left.y as u32 * self.scale) as u16,
+            width: ((bottom_right.x - top_left.x) as u32 * self.scale) as u16,
+            height: ((bottom_right.y - top_left.y) as u32 * self.scale) as u16,
+            delay: (delay.as_millis() / 10).min(u16::MAX as u128) as u16,
+            buffer: Cow::Owned(pixels(grid, top_left, bottom_right, self.scale, |ch| self.palette.index(ch))),
+            ..gif::Frame::default()
+        };
+        self.encoder.write_frame(&frame).map_err(gif_error)?;
+        self.shown = Some(grid.clone());
+        Ok(())
+    }
+}
+
+// The most frames a recording is turned into. Longer recordings show several ticks in each
+// frame, so the animation plays in about the same time however much the solution did.
+const MAX_FRAMES: usize = 500;
+
+// How long each frame of a recording is shown, and how long the last one stays up before it loops
+const FRAME_DELAY: Duration = Duration::from_millis(40);
+const LAST_FRAME_DELAY: Duration = Duration::from_secs(3);
+
+// Records the events, and when it's finished writes them out as name.gif, an animation with a
+// frame for each tick, and name.png, the last picture, in a directory. Visited cells are drawn
+// as •, and paths as ■, on the grid, just like on the terminal.
+pub struct RecordingVisualizer {
+    directory: PathBuf,
+    name: String,
+    palette: Palette,
+    scale: u32,
+    // the first grid, and the cells that changed at each tick after it
+    start: Option<Grid<char>>,
+    ticks: Vec<Vec<(Point2D<i32>, char)>>,
+    changes: Vec<(Point2D<i32>, char)>,
+    canvas: Grid<char>,
+}
+
+impl RecordingVisualizer {
+    pub fn new(directory: impl Into<PathBuf>, name: &str, palette: Palette, scale: u32) -> Self {
+        RecordingVisualizer {
+            directory: directory.into(),
+            name: name.to_string(),
+            palette,
+            scale,
+            start: None,
+            ticks: Vec::new(),
+            changes: Vec::new(),
+            canvas: Grid::new(0, 0, ' '),
+        }
+    }
+
+    fn draw(&mut self, p: Point2D<i32>, ch: char) {
+        if self.canvas.set(p, ch) {
+            self.changes.push((p, ch));
+        }
+    }
+
+    // Write out the GIF and the PNG of what has been recorded, returning the paths they were
+    // written to, and start a new recording
+    pub fn finish(&mut self) -> Result<Vec<PathBuf>, Error> {
+        let Some(mut grid) = self.start.take() else {
+            return Ok(Vec::new());
+        };
+        let mut ticks = std::mem::take(&mut self.ticks);
+        // changes since the last tick go in the last frame too
+        ticks.push(std::mem::take(&mut self.changes));
+        std::fs::create_dir_all(&self.directory)?;
+        let gif = self.directory.join(format!("{}.gif", self.name));
+        let png = self.directory.join(format!("{}.png", self.name));
+        let mut animation = Animation::create(&gif, grid.width(), grid.height(), self.palette.clone(), self.scale)?;
+        animation.frame(&grid, FRAME_DELAY)?;
+        let frames: Vec<_> = ticks.chunks(ticks.len().div_ceil(MAX_FRAMES)).collect();
+        for (i, frame) in frames.iter().enumerate() {
+            for &(p, ch) in frame.iter().flatten() {
+                grid[p] = ch;
+            }
+            let delay = if i + 1 == frames.len() { LAST_FRAME_DELAY } else { FRAME_DELAY };
+            animation.frame(&grid, delay)?;
+        }
+        drop(animation);
+        save_png(&png, &grid, &self.palette, self.scale)?;
+        Ok(vec![gif, png])
+    }
+}
+
+impl Visualizer for RecordingVisualizer {
+    // The first grid starts the recording, and any after it are recorded as changes to it
+    fn grid(&mut self, grid: &Grid<char>) {
+        let same_size = grid.width() == self.canvas.width() && grid.height() == self.canvas.height();
+        if self.start.is_none() || !same_size {
+            self.start = Some(grid.clone());
+            self.ticks.clear();
+            self.changes.clear();
+            self.canvas = grid.clone();
+            return;
+        }
+        for (p, &ch) in grid.iter() {
+            if self.canvas[p] != ch {
+                self.draw(p, ch);
+            }
+        }
+    }
+
+    fn visited(&mut self, p: Point2D<i32>) {
+        self.draw(p, '•');
+    }
+
+    fn path(&mut self, path: &[Point2D<i32>]) {
+        for &p in path {
+            self.draw(p, '■');
+        }
+    }
+
+    fn tick(&mut self) {
+        self.ticks.push(std::mem::take(&mut self.changes));
+    }
+}
+
+// Nothing can be returned from a drop, so report what was written, or why it couldn't be, on stderr
+impl Drop for RecordingVisualizer {
+    fn drop(&mut self) {
+        match self.finish() {
+            Ok(paths) => paths.iter().for_each(|path| eprintln!("Wrote {}", path.display())),
+            Err(e) => eprintln!("{}: {}", self.name, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_png(data: &[u8]) -> (u32, u32, Vec<u8>) {
+        let mut reader = png::Decoder::new(data).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        (info.width, info.height, pixels)
+    }
+
+    // the position and size of each frame, in pixels
+    fn decode_gif(data: &[u8]) -> Vec<(u16, u16, u16, u16)> {
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(data).unwrap();
+        let mut frames = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            frames.push((frame.left, frame.top, frame.width, frame.height));
+        }
+        frames
+    }
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::new([0, 0, 0]).with('#', [255, 255, 255]).with('x', [255, 0, 0]).with('#', [9, 9, 9]);
+        assert_eq!(palette.color('#'), [9, 9, 9]);
+        assert_eq!(palette.color('?'), [0, 0, 0]);
+        assert_eq!(palette.index('x'), 2);
+        assert_eq!(palette.table().unwrap(), vec![0, 0, 0, 9, 9, 9, 255, 0, 0]);
+        let crowded = (0..300).filter_map(char::from_u32).fold(Palette::new([0, 0, 0]), |palette, ch| palette.with(ch, [1, 2, 3]));
+        assert!(crowded.table().is_err());
+    }
+
+    #[test]
+    fn test_write_png() {
+        let grid = Grid::parse_chars("#.\n.#\n.x").unwrap();
+        let palette = Palette::new([0, 0, 255]).with('#', [255, 255, 255]).with('.', [0, 0, 0]);
+        let mut out = Vec::new();
+        write_png(&mut out, &grid, &palette, 2).unwrap();
+        let (width, height, pixels) = decode_png(&out);
+        assert_eq!((width, height), (4, 6));
+        let pixel = |x: usize, y: usize| pixels[(y * 4 + x) * 3..][..3].to_vec();
+        assert_eq!(pixel(1, 1), vec![255, 255, 255]);
+        assert_eq!(pixel(2, 1), vec![0, 0, 0]);
+        assert_eq!(pixel(3, 5), vec![0, 0, 255]);
+        assert!(write_png(Vec::new(), &grid, &palette, 0).is_err());
+    }
+
+    #[test]
+    fn test_animation_draws_changes() {
+        let mut grid = Grid::parse_chars("#####\n#...#\n#####").unwrap();
+        let mut out = Vec::new();
+        let mut animation = Animation::new(&mut out, 5, 3, Palette::default(), 3).unwrap();
+        animation.frame(&grid, FRAME_DELAY).unwrap();
+        grid[Point2D::new(2, 1)] = '•';
+        animation.frame(&grid, FRAME_DELAY).unwrap();
+        grid[Point2D::new(1, 1)] = '■';
+        grid[Point2D::new(3, 1)] = '■';
+        animation.frame(&grid, FRAME_DELAY).unwrap();
+        animation.frame(&grid, FRAME_DELAY).unwrap();
+        assert!(animation.frame(&Grid::new(2, 2, '.'), FRAME_DELAY).is_err());
+        drop(animation);
+        // the whole grid, then just the cells that changed, and a single pixel when nothing did
+        assert_eq!(decode_gif(&out), vec![(0, 0, 15, 9), (6, 3, 3, 3), (3, 3, 9, 3), (0, 0, 3, 3)]);
+    }
+
+    #[test]
+    fn test_recording_visualizer() {
+        let directory = std::env::temp_dir().join(format!("advent-render-{}", std::process::id()));
+        let mut visualizer = RecordingVisualizer::new(&directory, "maze", Palette::default(), 1);
+        visualizer.grid(&Grid::parse_chars("#####\n#S.E#\n#####").unwrap());
+        for x in 1..4 {
+            visualizer.visited(Point2D::new(x, 1));
+            visualizer.tick();
+        }
+        visualizer.path(&[Point2D::new(1, 1), Point2D::new(2, 1)]);
+        let paths = visualizer.finish().unwrap();
+        assert_eq!(paths, vec![directory.join("maze.gif"), directory.join("maze.png")]);
+        // the start, a frame for each tick, and the path drawn after the last tick
+        assert_eq!(decode_gif(&std::fs::read(&paths[0]).unwrap()).len(), 5);
+        let (width, height, pixels) = decode_png(&std::fs::read(&paths[1]).unwrap());
+        assert_eq!((width, height), (5, 3));
+        assert_eq!(pixels[(5 + 2) * 3..][..3], Palette::default().color('■'));
+        assert_eq!(pixels[(5 + 3) * 3..][..3], Palette::default().color('•'));
+        // nothing more to write once it's finished
+        assert_eq!(visualizer.finish().unwrap(), Vec::<PathBuf>::new());
+        drop(visualizer);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
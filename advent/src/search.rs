@@ -0,0 +1,688 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num::Zero;
+
+// Djikstra's shortest path algorithm abstraction
+// Requires an abstract node type,
+// a function to get the successors of a node with the cost of the step to each,
+// and a function to get the heuristic distance to the goal
+// Nodes are ordered only by priority, and wrapped in Reverse to make the BinaryHeap a min-heap
+#[derive(Debug, Clone, Eq)]
+struct PriorityNode<Node, Cost = u64> {
+    priority: Cost,
+    node: Node,
+}
+
+impl<Node: Eq, Cost: Ord> Ord for PriorityNode<Node, Cost> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+impl<Node: Eq, Cost: Ord> PartialOrd for PriorityNode<Node, Cost> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Node, Cost: PartialEq> PartialEq for PriorityNode<Node, Cost> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+// A path found by a search, from the start to the goal, and what it costs in total
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<Node, Cost = u64> {
+    pub nodes: Vec<Node>,
+    pub cost: Cost,
+}
+
+// A shortest path search, set up step by step and then run with `to` or `to_any`:
+//
+//     let path = Search::new(start, |node| successors(&map, node))
+//         .heuristic(|node| node.manhattan_distance(&goal))
+//         .on_visit(|node| draw(node))
+//         .to(goal);
+//
+// The successor function gives each node you can step to from a node, paired with the cost of that step,
+// as a Vec or any other iterator. Costs can be any type that adds up and has a zero, such as u64 or i32.
+// Without a heuristic this is Dijkstra's algorithm; with one it is A*, and the heuristic must never
+// guess more than the real remaining cost. The visit callback sees each node once, in the order
+// the search settles them.
+pub struct Search<Node, Cost, SuccessorFn, HeuristicFn, VisitFn> {
+    start: Node,
+    get_successors: SuccessorFn,
+    get_heuristic: HeuristicFn,
+    on_visit: VisitFn,
+    max_cost: Option<Cost>,
+}
+
+impl<Node, Cost, SuccessorFn> Search<Node, Cost, SuccessorFn, fn(&Node) -> Cost, fn(&Node)>
+where
+    Node: Eq + Hash + Clone,
+    Cost: Ord + Copy + Add<Output = Cost> + Zero,
+{
+    pub fn new<Successors>(start: Node, get_successors: SuccessorFn) -> Self
+    where
+        SuccessorFn: Fn(&Node) -> Successors,
+        Successors: IntoIterator<Item = (Node, Cost)>,
+    {
+        Search { start, get_successors, get_heuristic: |_| Cost::zero(), on_visit: |_| {}, max_cost: None }
+    }
+}
+
+impl<Node, Cost, SuccessorFn, HeuristicFn, VisitFn> Search<Node, Cost, SuccessorFn, HeuristicFn, VisitFn>
+where
+    Node: Eq + Hash + Clone,
+    Cost: Ord + Copy + Add<Output = Cost> + Zero,
+    HeuristicFn: Fn(&Node) -> Cost,
+    VisitFn: FnMut(&Node),
+{
+    // Guess the remaining cost from a node to the goal, to search towards the goal first
+    pub fn heuristic<NewHeuristicFn>(self, get_heuristic: NewHeuristicFn) -> Search<Node, Cost, SuccessorFn, NewHeuristicFn, VisitFn>
+    where
+        NewHeuristicFn: Fn(&Node) -> Cost,
+    {
+        Search {
+            start: self.start,
+            get_successors: self.get_successors,
+            get_heuristic,
+            on_visit: self.on_visit,
+            max_cost: self.max_cost,
+        }
+    }
+
+    // Call back with each node as the search settles it, to watch or animate the search
+    pub fn on_visit<NewVisitFn>(self, on_visit: NewVisitFn) -> Search<Node, Cost, SuccessorFn, HeuristicFn, NewVisitFn>
+    where
+        NewVisitFn: FnMut(&Node),
+    {
+        Search {
+            start: self.start,
+            get_successors: self.get_successors,
+            get_heuristic: self.get_heuristic,
+            on_visit,
+            max_cost: self.max_cost,
+        }
+    }
+
+    // Give up on any node that costs more than max_cost to reach
+    pub fn max_cost(mut self, max_cost: Cost) -> Self {
+        self.max_cost = Some(max_cost);
+        self
+    }
+
+    // The cheapest path from the start to goal, or None if there isn't one
+    pub fn to<Successors>(self, goal: Node) -> Option<Path<Node, Cost>>
+    where
+        SuccessorFn: Fn(&Node) -> Successors,
+        Successors: IntoIterator<Item = (Node, Cost)>,
+    {
+        self.to_any(|node| *node == goal)
+    }
+
+    // The cheapest path from the start to any node that is_goal accepts. The search stops as soon as it reaches one.
+    pub fn to_any<GoalFn, Successors>(self, is_goal: GoalFn) -> Option<Path<Node, Cost>>
+    where
+        GoalFn: Fn(&Node) -> bool,
+        SuccessorFn: Fn(&Node) -> Successors,
+        Successors: IntoIterator<Item = (Node, Cost)>,
+    {
+        let found = self.explore(is_goal, false);
+        let cost = found.cost?;
+        let mut path = vec![found.goals[0].clone()];
+        while let Some(previous) = found.previous.get(path.last().unwrap()) {
+            path.push(previous[0].clone());
+        }
+        path.reverse();
+        Some(Path { nodes: path, cost })
+    }
+
+    // Every cheapest path from the start to goal, or None if there isn't one
+    pub fn all_to<Successors>(self, goal: Node) -> Option<ShortestPathDag<Node, Cost>>
+    where
+        SuccessorFn: Fn(&Node) -> Successors,
+        Successors: IntoIterator<Item = (Node, Cost)>,
+    {
+        self.all_to_any(|node| *node == goal)
+    }
+
+    // Every cheapest path from the start to the nodes that is_goal accepts. All the goals that are
+    // equally cheap to reach are included, and the search stops once everything left costs more.
+    pub fn all_to_any<GoalFn, Successors>(self, is_goal: GoalFn) -> Option<ShortestPathDag<Node, Cost>>
+    where
+        GoalFn: Fn(&Node) -> bool,
+        SuccessorFn: Fn(&Node) -> Successors,
+        Successors: IntoIterator<Item = (Node, Cost)>,
+    {
+        let start = self.start.clone();
+        let found = self.explore(is_goal, true);
+        found.cost?;
+        Some(ShortestPathDag::new(start, found))
+    }
+
+    // The cheapest cost to every node that can be reached from the start, and the way back from each
+    pub fn all_distances<Successors>(self) -> ShortestPathTree<Node, Cost>
+    where
+        SuccessorFn: Fn(&Node) -> Successors,
+        Successors: IntoIterator<Item = (Node, Cost)>,
+    {
+        let found = self.explore(|_| false, true);
+        let previous = found.previous.into_iter().map(|(node, before)| (node, before[0].clone())).collect();
+        ShortestPathTree { distance: found.distance, previous }
+    }
+
+    // Settle nodes cheapest first until a goal is reached, or with all set, until every node that
+    // could be on an equally cheap path to a goal has been settled (which is every node if no goal is reached).
+    fn explore<GoalFn, Successors>(mut self, is_goal: GoalFn, all: bool) -> Found<Node, Cost>
+    where
+        GoalFn: Fn(&Node) -> bool,
+        SuccessorFn: Fn(&Node) -> Successors,
+        Successors: IntoIterator<Item = (Node, Cost)>,
+    {
+        // the queue is ordered by cost so far plus the heuristic, and distance is the cost so far.
+        // previous has the nodes a node can be reached from at its best distance, the first one first.
+        // The others are only needed with all set.
+        let mut queue = BinaryHeap::new();
+        let mut distance = HashMap::new();
+        let mut previous: HashMap<Node, Vec<Node>> = HashMap::new();
+        let mut visited = HashSet::new();
+        let mut goals = Vec::new();
+        let mut goal_cost = None;
+        queue.push(Reverse(PriorityNode { priority: (self.get_heuristic)(&self.start), node: self.start.clone() }));
+        distance.insert(self.start.clone(), Cost::zero());
+        while let Some(Reverse(PriorityNode { priority, node })) = queue.pop() {
+            // nothing still queued can be on a path as cheap as the one found
+            if goal_cost.is_some_and(|goal_cost| priority > goal_cost) {
+                break;
+            }
+            // a node can be queued more than once, and only the first (cheapest) time counts
+            if !visited.insert(node.clone()) {
+                continue;
+            }
+            (self.on_visit)(&node);
+            let cost = distance[&node];
+            if is_goal(&node) {
+                goals.push(node);
+                goal_cost = Some(cost);
+                if !all {
+                    break;
+                }
+                continue;
+            }
+            for (successor, step_cost) in (self.get_successors)(&node) {
+                let new_distance = cost + step_cost;
+                if self.max_cost.is_some_and(|max_cost| new_distance > max_cost) {
+                    continue;
+                }
+                match distance.get(&successor) {
+                    Some(&best) if new_distance > best => {}
+                    Some(&best) if new_distance == best => {
+                        // only the all-paths search keeps equally cheap predecessors. A step that costs
+                        // nothing back to a settled node could close a loop of nodes that each come before
+                        // the next, and then following previous never ends.
+                        let link = all && !(visited.contains(&successor) && leads_back(&previous, &node, &successor));
+                        if link {
+                            previous.entry(successor).or_default().push(node.clone());
+                        }
+                    }
+                    _ => {
+                        distance.insert(successor.clone(), new_distance);
+                        queue.push(Reverse(PriorityNode { priority: new_distance + (self.get_heuristic)(&successor), node: successor.clone() }));
+                        previous.insert(successor, vec![node.clone()]);
+                    }
+                }
+            }
+        }
+        Found { cost: goal_cost, goals, distance, previous }
+    }
+}
+
+// Whether following previous back from node can get to target
+fn leads_back<Node: Eq + Hash>(previous: &HashMap<Node, Vec<Node>>, node: &Node, target: &Node) -> bool {
+    let mut seen = HashSet::new();
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        if node == target {
+            return true;
+        }
+        if seen.insert(node) {
+            stack.extend(previous.get(node).into_iter().flatten());
+        }
+    }
+    false
+}
+
+// What a search found: the goals reached at the cheapest cost, if any, and the links back towards the start
+struct Found<Node, Cost> {
+    cost: Option<Cost>,
+    goals: Vec<Node>,
+    distance: HashMap<Node, Cost>,
+    previous: HashMap<Node, Vec<Node>>,
+}
+
+// The cheapest cost from a start to every node that can be reached from it, and the node before
+// each one on a cheapest path, which together make a tree of cheapest paths out from the start
+#[derive(Debug, Clone)]
+pub struct ShortestPathTree<Node, Cost = u64> {
+    pub distance: HashMap<Node, Cost>,
+    pub previous: HashMap<Node, Node>,
+}
+
+impl<Node, Cost> ShortestPathTree<Node, Cost>
+where
+    Node: Eq + Hash + Clone,
+    Cost: Copy,
+{
+    // The cheapest cost to node, or None if it can't be reached
+    pub fn distance_to(&self, node: &Node) -> Option<Cost> {
+        self.distance.get(node).copied()
+    }
+
+    // A cheapest path from the start to node, start first
+    pub fn path_to(&self, node: &Node) -> Option<Vec<Node>> {
+        self.distance.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(before) = self.previous.get(path.last().unwrap()) {
+            path.push(before.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// The cheapest cost from start to every node that can be reached, using Dijkstra's algorithm
+pub fn dijkstra_all<Node, Cost, SuccessorFn, Successors>(start: Node, get_successors: SuccessorFn) -> ShortestPathTree<Node, Cost>
+where
+    Node: Eq + Hash + Clone,
+    Cost: Ord + Copy + Add<Output = Cost> + Zero,
+    SuccessorFn: Fn(&Node) -> Successors,
+    Successors: IntoIterator<Item = (Node, Cost)>,
+{
+    Search::new(start, get_successors).all_distances()
+}
+
+// A search described by a type that holds what it needs, such as the puzzle's map, rather than by closures.
+// Implement the first three methods (and heuristic for A*) and the searches come for free:
+//
+//     impl SearchProblem for Maze {
+//         type Node = Point2D<i32>;
+//         type Cost = u64;
+//         fn start(&self) -> Point2D<i32> { self.start }
+//         fn successors(&self, p: Point2D<i32>) -> impl IntoIterator<Item = (Point2D<i32>, u64)> {
+//             self.grid.neighbors4(p).filter(|n| self.grid[*n] != '#').map(|n| (n, 1))
+//         }
+//         fn is_goal(&self, p: &Point2D<i32>) -> bool { *p == self.end }
+//     }
+//
+//     let steps = maze.shortest_path()?.cost;
+pub trait SearchProblem {
+    type Node: Eq + Hash + Clone;
+    type Cost: Ord + Copy + Add<Output = Self::Cost> + Zero;
+
+    fn start(&self) -> Self::Node;
+
+    // The nodes you can step to from node, each with the cost of the step
+    fn successors(&self, node: Self::Node) -> impl IntoIterator<Item = (Self::Node, Self::Cost)>;
+
+    fn is_goal(&self, node: &Self::Node) -> bool;
+
+    // A guess at the remaining cost to a goal that is never too high, to make the searches A*
+    fn heuristic(&self, _node: &Self::Node) -> Self::Cost {
+        Self::Cost::zero()
+    }
+
+    // The cheapest path from the start to a goal
+    fn shortest_path(&self) -> Option<Path<Self::Node, Self::Cost>> {
+        Search::new(self.start(), |node: &Self::Node| self.successors(node.clone()))
+            .heuristic(|node| self.heuristic(node))
+            .to_any(|node| self.is_goal(node))
+    }
+
+    // Every cheapest path from the start to the goals
+    fn all_shortest_paths(&self) -> Option<ShortestPathDag<Self::Node, Self::Cost>> {
+        Search::new(self.start(), |node: &Self::Node| self.successors(node.clone()))
+            .heuristic(|node| self.heuristic(node))
+            .all_to_any(|node| self.is_goal(node))
+    }
+
+    // The cheapest cost from the start to every node that can be reached
+    fn all_distances(&self) -> ShortestPathTree<Self::Node, Self::Cost> {
+        Search::new(self.start(), |node: &Self::Node| self.successors(node.clone())).all_distances()
+    }
+}
+
+// Every cheapest path from a start to the goals, as the nodes on them and the links between those nodes.
+// Steps that cost nothing must not form a loop, or the links could go round it forever.
+#[derive(Debug, Clone)]
+pub struct ShortestPathDag<Node, Cost = u64> {
+    start: Node,
+    cost: Cost,
+    goals: Vec<Node>,
+    // for each node on a cheapest path, the nodes just before it on a cheapest path
+    previous: HashMap<Node, Vec<Node>>,
+    nodes: HashSet<Node>,
+}
+
+impl<Node, Cost> ShortestPathDag<Node, Cost>
+where
+    Node: Eq + Hash + Clone,
+    Cost: Copy,
+{
+    fn new(start: Node, found: Found<Node, Cost>) -> Self {
+        // walk back from the goals to keep only the links on cheapest paths
+        let mut nodes = HashSet::new();
+        let mut previous = HashMap::new();
+        let mut stack = found.goals.clone();
+        while let Some(node) = stack.pop() {
+            if !nodes.insert(node.clone()) {
+                continue;
+            }
+            if let Some(before) = found.previous.get(&node) {
+                stack.extend(before.iter().cloned());
+                previous.insert(node, before.clone());
+            }
+        }
+        ShortestPathDag { start, cost: found.cost.unwrap(), goals: found.goals, previous, nodes }
+    }
+
+    // The cost of every one of the paths
+    pub fn cost(&self) -> Cost {
+        self.cost
+    }
+
+    // The goals reached, which all cost the same
+    pub fn goals(&self) -> &[Node] {
+        &self.goals
+    }
+
+    // Every node that is on at least one of the paths
+    pub fn nodes(&self) -> &HashSet<Node> {
+        &self.nodes
+    }
+
+    pub fn contains(&self, node: &Node) -> bool {
+        self.nodes.contains(node)
+    }
+
+    // Each of the paths in turn, start first. There can be very many of them.
+    pub fn paths(&self) -> impl Iterator<Item = Vec<Node>> + '_ {
+        // each entry on the stack is the end of a path, built backwards from a goal
+        let mut stack: Vec<Vec<Node>> = self.goals.iter().map(|goal| vec![goal.clone()]).collect();
+        std::iter::from_fn(move || {
+            while let Some(mut path) = stack.pop() {
+                match self.previous.get(path.last().unwrap()) {
+                    None => {
+                        path.reverse();
+                        return Some(path);
+                    }
+                    Some(before) => {
+                        for node in before.iter().rev() {
+                            let mut longer = path.clone();
+                            longer.push(node.clone());
+                            stack.push(longer);
+                        }
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    // How many paths there are, without listing them
+    pub fn count_paths(&self) -> u64 {
+        // count the ways to reach each node from the start, going forward in an order where
+        // every node comes after all the nodes before it
+        let mut next: HashMap<&Node, Vec<&Node>> = HashMap::new();
+        let mut waiting: HashMap<&Node, usize> = HashMap::new();
+        for (node, before) in &self.previous {
+            waiting.insert(node, before.len());
+            for b in before {
+                next.entry(b).or_default().push(node);
+            }
+        }
+        let mut ways: HashMap<&Node, u64> = HashMap::from([(&self.start, 1)]);
+        let mut ready = vec![&self.start];
+        while let Some(node) = ready.pop() {
+            let here = ways[node];
+            for &after in next.get(node).into_iter().flatten() {
+                *ways.entry(after).or_default() += here;
+                let count = waiting.get_mut(after).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(after);
+                }
+            }
+        }
+        self.goals.iter().map(|goal| ways.get(goal).copied().unwrap_or(0)).sum()
+    }
+}
+
+// Pair each neighbor with the distance to it, for the functions that take the two separately
+fn with_distances<'a, Node, NeighborFn, DistanceFn>(get_neighbors: &'a NeighborFn, get_distance: &'a DistanceFn) -> impl Fn(&Node) -> Vec<(Node, u64)> + 'a
+where
+    NeighborFn: Fn(&Node) -> Vec<Node>,
+    DistanceFn: Fn(&Node, &Node) -> u64,
+{
+    move |node| {
+        get_neighbors(node).into_iter().map(|neighbor| {
+            let distance = get_distance(node, &neighbor);
+            (neighbor, distance)
+        }).collect()
+    }
+}
+
+// The nodes on the cheapest path from start to goal, or None if there isn't one
+pub fn shortest_path<Node, NeighborFn, DistanceFn, HeuristicFn>(start: Node, goal: Node, get_neighbors: NeighborFn, get_distance: DistanceFn, get_heuristic: HeuristicFn) -> Option<Vec<Node>>
+where
+    Node: std::cmp::Eq + std::hash::Hash + std::clone::Clone,
+    NeighborFn: Fn(&Node) -> Vec<Node>,
+    DistanceFn: Fn(&Node, &Node) -> u64,
+    HeuristicFn: Fn(&Node, &Node) -> u64,
+{
+    Search::new(start, with_distances(&get_neighbors, &get_distance))
+        .heuristic(|node| get_heuristic(node, &goal))
+        .to(goal.clone())
+        .map(|path| path.nodes)
+}
+
+// Every cheapest path from start to goal, or None if there isn't one
+pub fn all_shortest_paths<Node, NeighborFn, DistanceFn, HeuristicFn>(start: Node, goal: Node, get_neighbors: NeighborFn, get_distance: DistanceFn, get_heuristic: HeuristicFn) -> Option<ShortestPathDag<Node>>
+where
+    Node: std::cmp::Eq + std::hash::Hash + std::clone::Clone,
+    NeighborFn: Fn(&Node) -> Vec<Node>,
+    DistanceFn: Fn(&Node, &Node) -> u64,
+    HeuristicFn: Fn(&Node, &Node) -> u64,
+{
+    Search::new(start, with_distances(&get_neighbors, &get_distance))
+        .heuristic(|node| get_heuristic(node, &goal))
+        .all_to(goal.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortest_path() {
+        #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+        struct Node {
+            id: u32,
+        }
+
+        let start = Node { id: 1 };
+        let goal = Node { id: 4 };
+
+        let get_neighbors = |node: &Node| -> Vec<Node> {
+            match node.id {
+                1 => vec![Node { id: 2 }, Node { id: 3 }],
+                2 => vec![Node { id: 4 }],
+                3 => vec![Node { id: 4 }],
+                _ => vec![],
+            }
+        };
+
+        let get_distance = |_node1: &Node, _node2: &Node| -> u64 {
+            1
+        };
+
+        let get_heuristic = |_node1: &Node, _node2: &Node| -> u64 {
+            0
+        };
+
+        let path = shortest_path(start.clone(), goal.clone(), get_neighbors, get_distance, get_heuristic);
+        assert!(path.is_some());
+        let path = path.unwrap();
+        assert_eq!(path, vec![start, Node { id: 2 }, goal]);
+    }
+
+    // a small weighted graph where the direct edge is not the cheapest way
+    fn weighted_successors(node: &u32) -> Vec<(u32, u64)> {
+        match node {
+            1 => vec![(2, 2), (4, 10)],
+            2 => vec![(3, 2)],
+            3 => vec![(4, 2)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_search_path_and_cost() {
+        let path = Search::new(1, weighted_successors).to(4).unwrap();
+        assert_eq!(path, Path { nodes: vec![1, 2, 3, 4], cost: 6 });
+        // the start is a path of its own to itself
+        assert_eq!(Search::new(1, weighted_successors).to(1), Some(Path { nodes: vec![1], cost: 0 }));
+        assert_eq!(Search::new(4, weighted_successors).to(1), None);
+        // the first goal reached is the cheapest one
+        let path = Search::new(1, weighted_successors).to_any(|&node| node >= 3).unwrap();
+        assert_eq!(path.nodes, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_search_heuristic_visit_and_max_cost() {
+        // an admissible heuristic still finds the cheapest path, and the callback sees every settled node once
+        let mut visited = Vec::new();
+        let path = Search::new(1, weighted_successors)
+            .heuristic(|&node| 2 * (4 - node as u64))
+            .on_visit(|&node| visited.push(node))
+            .to(4)
+            .unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(visited, vec![1, 2, 3, 4]);
+        assert_eq!(Search::new(1, weighted_successors).max_cost(5).to(4), None);
+        assert_eq!(Search::new(1, weighted_successors).max_cost(6).to(4).map(|path| path.cost), Some(6));
+    }
+
+    #[test]
+    fn test_search_successor_iterator_and_signed_cost() {
+        // a number line where stepping right costs 3 and stepping left costs 1, with successors as an iterator
+        let successors = |&n: &i32| [(n + 1, 3), (n - 1, 1)].into_iter().filter(|&(m, _)| (-5..=5).contains(&m));
+        let path = Search::new(0, successors).to(2).unwrap();
+        assert_eq!(path.nodes, vec![0, 1, 2]);
+        assert_eq!(path.cost, 6i32);
+        assert_eq!(Search::new(0, successors).to_any(|&n| n.abs() == 3).map(|path| path.cost), Some(3));
+    }
+
+    // two equally cheap ways round a diamond, then a choice of two equal steps, and a dearer shortcut
+    fn diamond_successors(node: &u32) -> Vec<(u32, u64)> {
+        match node {
+            1 => vec![(2, 1), (3, 1), (5, 5)],
+            2 => vec![(4, 1)],
+            3 => vec![(4, 1)],
+            4 => vec![(5, 1), (6, 1)],
+            6 => vec![(5, 0), (7, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_search_all_paths() {
+        let dag = Search::new(1, diamond_successors).all_to(5).unwrap();
+        assert_eq!(dag.cost(), 3);
+        assert_eq!(dag.nodes(), &HashSet::from([1, 2, 3, 4, 5, 6]));
+        assert!(!dag.contains(&7));
+        let mut paths: Vec<Vec<u32>> = dag.paths().collect();
+        paths.sort();
+        assert_eq!(paths, vec![vec![1, 2, 4, 5], vec![1, 2, 4, 6, 5], vec![1, 3, 4, 5], vec![1, 3, 4, 6, 5]]);
+        assert_eq!(dag.count_paths(), 4);
+        // both goals cost the same, so both are included
+        let dag = Search::new(1, diamond_successors).all_to_any(|&node| node == 2 || node == 3).unwrap();
+        assert_eq!(dag.goals().len(), 2);
+        assert_eq!(dag.count_paths(), 2);
+        assert!(Search::new(5, diamond_successors).all_to(1).is_none());
+        let dag = Search::new(1, diamond_successors).all_to(1).unwrap();
+        assert_eq!((dag.cost(), dag.count_paths()), (0, 1));
+    }
+
+    // 1 and 2 can step back and forth for nothing, so each can be reached from the other at its best distance
+    fn zero_cost_loop_successors(node: &u32) -> Vec<(u32, u64)> {
+        match node {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 0), (3, 1)],
+            2 => vec![(1, 0), (3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_search_zero_cost_loop() {
+        let path = Search::new(0, zero_cost_loop_successors).to(3).unwrap();
+        assert_eq!((path.nodes, path.cost), (vec![0, 1, 3], 1));
+        let tree = dijkstra_all(0, zero_cost_loop_successors);
+        assert_eq!(tree.path_to(&0), Some(vec![0]));
+        assert_eq!(tree.path_to(&2), Some(vec![0, 1, 2]));
+        let dag = Search::new(0, zero_cost_loop_successors).all_to(3).unwrap();
+        let mut paths: Vec<Vec<u32>> = dag.paths().collect();
+        paths.sort();
+        assert_eq!(paths, vec![vec![0, 1, 2, 3], vec![0, 1, 3]]);
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        let tree = dijkstra_all(1, diamond_successors);
+        assert_eq!(tree.distance.len(), 7);
+        assert_eq!(tree.distance_to(&5), Some(3));
+        assert_eq!(tree.distance_to(&7), Some(4));
+        assert_eq!(tree.path_to(&7), Some(vec![1, 2, 4, 6, 7]));
+        assert_eq!(tree.path_to(&1), Some(vec![1]));
+        assert_eq!(dijkstra_all(6, diamond_successors).path_to(&1), None);
+        // a search that gives up past a cost only reaches the nodes within it
+        let tree = Search::new(1, weighted_successors).max_cost(4).all_distances();
+        assert_eq!(tree.distance, HashMap::from([(1, 0), (2, 2), (3, 4)]));
+    }
+
+    // the diamond graph, held in a struct rather than a function
+    struct Diamond {
+        goal: u32,
+    }
+
+    impl SearchProblem for Diamond {
+        type Node = u32;
+        type Cost = u64;
+
+        fn start(&self) -> u32 {
+            1
+        }
+
+        fn successors(&self, node: u32) -> impl IntoIterator<Item = (u32, u64)> {
+            diamond_successors(&node)
+        }
+
+        fn is_goal(&self, node: &u32) -> bool {
+            *node == self.goal
+        }
+    }
+
+    #[test]
+    fn test_search_problem() {
+        let diamond = Diamond { goal: 5 };
+        assert_eq!(diamond.shortest_path().map(|path| path.cost), Some(3));
+        assert_eq!(diamond.all_shortest_paths().unwrap().count_paths(), 4);
+        assert_eq!(diamond.all_distances().distance_to(&7), Some(4));
+        assert_eq!(Diamond { goal: 8 }.shortest_path(), None);
+    }
+}
@@ -0,0 +1,140 @@
+use std::time::{Duration, Instant};
+
+use crate::bench::{bench, Measurement};
+use crate::{get_commandline_arguments, set_render, set_visualize, try_read_file_to_string, Error};
+
+// A day's puzzle, split into reading the input once and solving each part from it.
+// Days that do their parsing inside part1/part2 can use the raw contents as the Input.
+// Malformed input is reported from parse, with the line it was found on.
+pub trait Solution {
+    type Input;
+
+    fn parse(contents: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> String;
+    fn part2(input: &Self::Input) -> String;
+}
+
+// The answer for one part of a day, and how long it took to solve
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u32,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+// Parse the contents once, then solve each of the requested parts
+pub fn solve<S: Solution>(contents: &str, parts: &[u32]) -> Result<Vec<PartResult>, Error> {
+    let input = S::parse(contents)?;
+    Ok(parts.iter().map(|&part| {
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&input),
+            2 => S::part2(&input),
+            _ => panic!("There is no part {}", part),
+        };
+        PartResult { part, answer, duration: start.elapsed() }
+    }).collect())
+}
+
+// A registry entry for a day, with the Solution type erased so days can be kept in one list
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    pub name: &'static str,
+    pub solve: fn(&str, &[u32]) -> Result<Vec<PartResult>, Error>,
+    pub bench: fn(&str, usize) -> Result<Vec<Measurement>, Error>,
+}
+
+impl Day {
+    pub fn new<S: Solution>(number: u32, name: &'static str) -> Self {
+        Self { number, name, solve: solve::<S>, bench: bench::<S> }
+    }
+}
+
+pub fn print_part_result(result: &PartResult) {
+    println!("Part {}:\n{}\n\tTook {:?}", result.part, result.answer, result.duration);
+}
+
+// The shared main for each day's binary: read the input file named on the command line and run both parts.
+// --visualize anywhere on the command line animates the days that have something to show, and
+// --render <directory> writes pictures of them there instead.
+pub fn run<S: Solution>(name: &str) {
+    let usage = || -> ! {
+        eprintln!("Usage: {} [--visualize] [--render <directory>] <input file>", name);
+        std::process::exit(1);
+    };
+    let mut args = Vec::new();
+    let mut rest = get_commandline_arguments().into_iter();
+    while let Some(arg) = rest.next() {
+        if arg == "--visualize" {
+            set_visualize(true);
+        } else if arg == "--render" {
+            set_render(Some(rest.next().unwrap_or_else(|| usage()).into()));
+        } else {
+            args.push(arg);
+        }
+    }
+    // the first argument is the input file name
+    if args.is_empty() {
+        usage();
+    }
+    let results = try_read_file_to_string(&args[0])
+        .and_then(|contents| solve::<S>(&contents, &[1, 2]))
+        .map_err(|e| e.in_file(args[0].as_str()).in_day(name));
+    match results {
+        Ok(results) => results.iter().for_each(print_part_result),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+
+        fn parse(contents: &str) -> Result<Vec<i32>, Error> {
+            crate::try_split_line_into(contents)
+        }
+
+        fn part1(input: &Vec<i32>) -> String {
+            input.iter().sum::<i32>().to_string()
+        }
+
+        fn part2(input: &Vec<i32>) -> String {
+            input.iter().product::<i32>().to_string()
+        }
+    }
+
+    #[test]
+    fn test_solve_both_parts() {
+        let results = solve::<Sum>("2 3 4", &[1, 2]).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].part, 1);
+        assert_eq!(results[0].answer, "9");
+        assert_eq!(results[1].part, 2);
+        assert_eq!(results[1].answer, "24");
+    }
+
+    #[test]
+    fn test_day_registry_entry() {
+        let day = Day::new::<Sum>(7, "day07");
+        assert_eq!(day.number, 7);
+        assert_eq!(day.name, "day07");
+        let results = (day.solve)("5 6", &[2]).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].answer, "30");
+    }
+
+    #[test]
+    fn test_solve_reports_parse_errors() {
+        let error = solve::<Sum>("2 three 4", &[1, 2]).unwrap_err();
+        assert_eq!(error.to_string(), "column 3: can't parse `three`: invalid digit found in string");
+    }
+}
@@ -0,0 +1,332 @@
+use std::fmt::{self, Write as _};
+use std::io::{self, IsTerminal, Stdout, Write};
+
+use crate::{Grid, Point2D};
+
+const RESET: &str = "\x1B[0m";
+const CLEAR: &str = "\x1B[2J";
+const HIDE_CURSOR: &str = "\x1B[?25l";
+const SHOW_CURSOR: &str = "\x1B[?25h";
+
+// The colors every terminal knows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Color {
+    #[default]
+    Default,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    // The color's number in escape codes, added to 30 for text or 40 for the background
+    fn code(self) -> u8 {
+        match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::Default => 9,
+            // the bright version of black
+            Color::Gray => 60,
+        }
+    }
+}
+
+// How text is drawn: its color, the background color, and any emphasis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    pub fg: Color,
+    pub bg: Color,
+    pub bold: bool,
+    pub dim: bool,
+    pub underline: bool,
+}
+
+impl Style {
+    // The terminal's own colors, with no emphasis
+    pub const PLAIN: Style = Style { fg: Color::Default, bg: Color::Default, bold: false, dim: false, underline: false };
+
+    // Text in the given color
+    pub const fn fg(color: Color) -> Style {
+        Style { fg: color, ..Style::PLAIN }
+    }
+
+    pub const fn on(self, background: Color) -> Style {
+        Style { bg: background, ..self }
+    }
+
+    pub const fn bold(self) -> Style {
+        Style { bold: true, ..self }
+    }
+
+    pub const fn dim(self) -> Style {
+        Style { dim: true, ..self }
+    }
+
+    pub const fn underline(self) -> Style {
+        Style { underline: true, ..self }
+    }
+
+    // The escape code that switches to this style, whatever the style was before
+    pub fn escape(&self) -> String {
+        let mut codes = vec!["0".to_string()];
+        for (on, code) in [(self.bold, "1"), (self.dim, "2"), (self.underline, "4")] {
+            if on {
+                codes.push(code.to_string());
+            }
+        }
+        if self.fg != Color::Default {
+            codes.push((30 + self.fg.code()).to_string());
+        }
+        if self.bg != Color::Default {
+            codes.push((40 + self.bg.code()).to_string());
+        }
+        format!("\x1B[{}m", codes.join(";"))
+    }
+
+    // The text in this style, and then back to plain
+    pub fn paint(&self, text: impl fmt::Display) -> String {
+        format!("{}{}{}", self.escape(), text, RESET)
+    }
+}
+
+// One character on the screen, and the style to draw it in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell { ch: ' ', style: Style::PLAIN }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char(self.ch)
+    }
+}
+
+// A picture to show on the terminal, built up a cell at a time and then drawn in one go.
+// Anything put outside the frame is left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+}
+
+impl Frame {
+    // A blank frame
+    pub fn new(width: i32, height: i32) -> Self {
+        Frame { cells: Grid::new(width, height, Cell::default()) }
+    }
+
+    // A frame showing a grid, with the first character of each cell in the same style
+    pub fn from_grid<T: fmt::Display>(grid: &Grid<T>, style: Style) -> Self {
+        Frame { cells: grid.map(|_, value| Cell { ch: value.to_string().chars().next().unwrap_or(' '), style }) }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> i32 {
+        self.cells.height()
+    }
+
+    pub fn get(&self, p: Point2D<i32>) -> Option<&Cell> {
+        self.cells.get(p)
+    }
+
+    pub fn set(&mut self, p: Point2D<i32>, ch: char, style: Style) {
+        self.cells.set(p, Cell { ch, style });
+    }
+
+    // Write text going right from p, one character per cell
+    pub fn text(&mut self, p: Point2D<i32>, text: &str, style: Style) {
+        for (i, ch) in text.chars().enumerate() {
+            self.set(Point2D::new(p.x + i as i32, p.y), ch, style);
+        }
+    }
+
+    pub fn fill(&mut self, ch: char, style: Style) {
+        self.cells = Grid::new(self.width(), self.height(), Cell { ch, style });
+    }
+}
+
+// The frame as plain text, one line per row
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+// Draws frames at the top left of a terminal, sending only the cells that changed since the last
+// frame so an animation doesn't flicker. When the output isn't a terminal, such as when it's piped
+// to a file, escape codes would only get in the way, so just the last frame is written out, as
+// plain text, when the terminal is finished with.
+pub struct Terminal<W: Write = Stdout> {
+    out: W,
+    tty: bool,
+    // what is on the screen, or the frame waiting to be written out
+    shown: Option<Frame>,
+}
+
+impl Terminal<Stdout> {
+    // Draw on standard output, as a terminal if it is one
+    pub fn stdout() -> Self {
+        let out = io::stdout();
+        let tty = out.is_terminal();
+        Terminal::new(out, tty)
+    }
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, tty: bool) -> Self {
+        Terminal { out, tty, shown: None }
+    }
+
+    pub fn is_tty(&self) -> bool {
+        self.tty
+    }
+
+    pub fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        if !self.tty {
+            self.shown = Some(frame.clone());
+            return Ok(());
+        }
+        // a frame of a different size starts again on a clear screen
+        let shown = self.shown.as_ref().filter(|shown| shown.width() == frame.width() && shown.height() == frame.height());
+        let mut out = String::new();
+        if shown.is_none() {
+            out.push_str(CLEAR);
+        }
+        let mut cursor = None;
+        let mut style = None;
+        for (p, cell) in frame.cells.iter() {
+            if shown.is_some_and(|shown| shown.get(p) == Some(cell)) {
+                continue;
+            }
+            // drawing a character moves the cursor on by one, so neighbouring changes don't need a move
+            if cursor != Some(p) {
+                write!(out, "\x1B[{};{}H", p.y + 1, p.x + 1).unwrap();
+            }
+            if style != Some(cell.style) {
+                out.push_str(&cell.style.escape());
+                style = Some(cell.style);
+            }
+            out.push(cell.ch);
+            cursor = Some(Point2D::new(p.x + 1, p.y));
+        }
+        if style.is_some() {
+            out.push_str(RESET);
+        }
+        self.out.write_all(out.as_bytes())?;
+        self.out.flush()?;
+        self.shown = Some(frame.clone());
+        Ok(())
+    }
+
+    // Put the cursor on the line after the last frame, or write out the last frame if this
+    // isn't a terminal. The next frame drawn starts on a clear screen.
+    pub fn finish(&mut self) -> io::Result<()> {
+        let Some(shown) = self.shown.take() else {
+            return Ok(());
+        };
+        if self.tty {
+            write!(self.out, "\x1B[{};1H", shown.height() + 1)?;
+        } else {
+            write!(self.out, "{}", shown)?;
+        }
+        self.out.flush()
+    }
+}
+
+impl<W: Write> Drop for Terminal<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+// Keeps the cursor hidden until it is dropped, even if that's because of a panic.
+// Does nothing when standard output isn't a terminal.
+pub struct HiddenCursor {
+    tty: bool,
+}
+
+pub fn hide_cursor() -> HiddenCursor {
+    let tty = io::stdout().is_terminal();
+    if tty {
+        print!("{}", HIDE_CURSOR);
+        let _ = io::stdout().flush();
+    }
+    HiddenCursor { tty }
+}
+
+impl Drop for HiddenCursor {
+    fn drop(&mut self) {
+        if self.tty {
+            print!("{}", SHOW_CURSOR);
+            let _ = io::stdout().flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_escape() {
+        assert_eq!(Style::PLAIN.escape(), "\x1B[0m");
+        assert_eq!(Style::fg(Color::Red).escape(), "\x1B[0;31m");
+        assert_eq!(Style::fg(Color::Gray).on(Color::Blue).bold().escape(), "\x1B[0;1;90;44m");
+        assert_eq!(Style::fg(Color::Green).paint('■'), "\x1B[0;32m■\x1B[0m");
+    }
+
+    #[test]
+    fn test_terminal_draws_changes() {
+        let mut out = Vec::new();
+        let mut terminal = Terminal::new(&mut out, true);
+        let mut frame = Frame::from_grid(&Grid::parse_chars("#.#\n...").unwrap(), Style::PLAIN);
+        terminal.draw(&frame).unwrap();
+        // the next frame only moves to the changed cells, and the second of two side by side needs no move
+        frame.set(Point2D::new(1, 0), 'x', Style::fg(Color::Red));
+        frame.set(Point2D::new(2, 0), 'y', Style::fg(Color::Red));
+        frame.set(Point2D::new(0, 1), 'z', Style::PLAIN);
+        terminal.draw(&frame).unwrap();
+        // drawing the same frame again sends nothing
+        terminal.draw(&frame).unwrap();
+        terminal.finish().unwrap();
+        drop(terminal);
+        let first = "\x1B[2J\x1B[1;1H\x1B[0m#.#\x1B[2;1H...\x1B[0m";
+        let second = "\x1B[1;2H\x1B[0;31mxy\x1B[2;1H\x1B[0mz\x1B[0m";
+        assert_eq!(String::from_utf8(out).unwrap(), format!("{}{}\x1B[3;1H", first, second));
+    }
+
+    #[test]
+    fn test_terminal_fallback() {
+        let mut out = Vec::new();
+        let mut terminal = Terminal::new(&mut out, false);
+        let mut frame = Frame::new(4, 2);
+        frame.fill('.', Style::PLAIN);
+        terminal.draw(&frame).unwrap();
+        frame.text(Point2D::new(1, 1), "hi!", Style::fg(Color::Green));
+        terminal.draw(&frame).unwrap();
+        // dropping the terminal writes out just the last frame, with no escape codes
+        drop(terminal);
+        assert_eq!(String::from_utf8(out).unwrap(), "....\n.hi!\n");
+    }
+}
@@ -0,0 +1,140 @@
+use std::io::{Stdout, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::render::{Palette, RecordingVisualizer};
+use crate::term::{self, Color, Frame, HiddenCursor, Style, Terminal};
+use crate::{Grid, Point2D};
+
+// Set by the --visualize flag
+static VISUALIZE: AtomicBool = AtomicBool::new(false);
+
+// Set by the --render flag
+static RENDER: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+// Where a solution reports what it's doing, for anyone who wants to watch. Every event does nothing
+// unless a visualizer overrides it, so solving stays quiet when nobody is watching.
+pub trait Visualizer {
+    // The grid everything else is drawn on
+    fn grid(&mut self, _grid: &Grid<char>) {}
+
+    // A cell the solution has looked at
+    fn visited(&mut self, _p: Point2D<i32>) {}
+
+    // A path the solution has found
+    fn path(&mut self, _path: &[Point2D<i32>]) {}
+
+    // Show what has happened since the last tick
+    fn tick(&mut self) {}
+}
+
+// The visualizer for when nobody is watching
+pub struct NoVisualizer;
+
+impl Visualizer for NoVisualizer {}
+
+// Animates the events on a terminal: the grid in white, visited cells in red and paths in green,
+// pausing for a moment at each tick so there's time to see them. When the output isn't a terminal
+// only the final picture is written.
+pub struct TerminalVisualizer<W: Write = Stdout> {
+    terminal: Terminal<W>,
+    frame: Frame,
+    delay: Duration,
+    // declared after the terminal, so the cursor comes back once the last frame is finished
+    _cursor: Option<HiddenCursor>,
+}
+
+impl TerminalVisualizer<Stdout> {
+    pub fn stdout(delay: Duration) -> Self {
+        let terminal = Terminal::stdout();
+        let cursor = terminal.is_tty().then(term::hide_cursor);
+        TerminalVisualizer { _cursor: cursor, ..TerminalVisualizer::new(terminal, delay) }
+    }
+}
+
+impl<W: Write> TerminalVisualizer<W> {
+    pub fn new(terminal: Terminal<W>, delay: Duration) -> Self {
+        TerminalVisualizer { terminal, frame: Frame::new(0, 0), delay, _cursor: None }
+    }
+}
+
+impl<W: Write> Visualizer for TerminalVisualizer<W> {
+    fn grid(&mut self, grid: &Grid<char>) {
+        self.frame = Frame::from_grid(grid, Style::fg(Color::White));
+    }
+
+    fn visited(&mut self, p: Point2D<i32>) {
+        self.frame.set(p, '•', Style::fg(Color::Red));
+    }
+
+    fn path(&mut self, path: &[Point2D<i32>]) {
+        for &p in path {
+            self.frame.set(p, '■', Style::fg(Color::Green));
+        }
+    }
+
+    fn tick(&mut self) {
+        self.terminal.draw(&self.frame).unwrap();
+        // without a terminal there's nothing to watch, so don't wait
+        if self.terminal.is_tty() {
+            std::thread::sleep(self.delay);
+        }
+    }
+}
+
+// Turn visualizing on or off for the visualizers made from now on
+pub fn set_visualize(on: bool) {
+    VISUALIZE.store(on, Ordering::Relaxed);
+}
+
+// Whether to visualize: if the --visualize flag was given, or ADVENT_VISUALIZE is set to anything but 0
+pub fn visualize_enabled() -> bool {
+    VISUALIZE.load(Ordering::Relaxed) || std::env::var("ADVENT_VISUALIZE").is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+// Write pictures of what the days show to a directory, for the visualizers made from now on
+pub fn set_render(directory: Option<PathBuf>) {
+    *RENDER.lock().unwrap() = directory;
+}
+
+// Where to write pictures: the directory given with --render, or ADVENT_RENDER if it's set
+pub fn render_directory() -> Option<PathBuf> {
+    let set = RENDER.lock().unwrap().clone();
+    set.or_else(|| std::env::var_os("ADVENT_RENDER").filter(|value| !value.is_empty()).map(PathBuf::from))
+}
+
+// A visualizer for what the solution called name shows. If there's a directory to render to, it
+// records a GIF and a PNG named after it there; otherwise it animates on the terminal if
+// visualizing is enabled, or does nothing if not.
+pub fn visualizer(name: &str) -> Box<dyn Visualizer> {
+    if let Some(directory) = render_directory() {
+        Box::new(RecordingVisualizer::new(directory, name, Palette::default(), 4))
+    } else if visualize_enabled() {
+        Box::new(TerminalVisualizer::stdout(Duration::from_millis(1)))
+    } else {
+        Box::new(NoVisualizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terminal_visualizer() {
+        let mut out = Vec::new();
+        let mut visualizer = TerminalVisualizer::new(Terminal::new(&mut out, false), Duration::from_secs(60));
+        visualizer.grid(&Grid::parse_chars("#####\n#S.E#\n#####").unwrap());
+        for x in 1..4 {
+            visualizer.visited(Point2D::new(x, 1));
+            visualizer.tick();
+        }
+        visualizer.path(&[Point2D::new(1, 1), Point2D::new(2, 1)]);
+        visualizer.tick();
+        // not on a terminal, so there's no waiting, and only the last picture is written
+        drop(visualizer);
+        assert_eq!(String::from_utf8(out).unwrap(), "#####\n#■■•#\n#####\n");
+    }
+}
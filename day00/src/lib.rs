@@ -0,0 +1,28 @@
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(contents: &str) -> String {
+    1.to_string()
+}
+
+#[allow(unused_variables)]
+pub fn part2(contents: &str) -> String {
+    2.to_string()
+}
+
+pub struct Day00;
+
+impl advent::Solution for Day00 {
+    type Input = String;
+
+    fn parse(contents: &str) -> Result<String, advent::Error> {
+        Ok(contents.to_string())
+    }
+
+    fn part1(input: &String) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &String) -> String {
+        part2(input)
+    }
+}
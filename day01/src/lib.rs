@@ -0,0 +1,69 @@
+// use the advent package
+use advent::parse;
+
+fn get_arrays(contents: &str) -> Result<(Vec<i32>, Vec<i32>), advent::Error> {
+    // each line is a pair of numbers, the first for the first array and the second for the second
+    Ok(advent::try_parse_lines(contents, parse::ints_array::<i32, 2>)?.into_iter().map(|[a, b]| (a, b)).unzip())
+}
+
+fn array_differences(firsts: &[i32], seconds: &[i32]) -> Vec<i32> {
+    // get the absolute value of the differences of each pair of numbers between the two arrays
+    let mut differences = Vec::new();
+    for i in 0..firsts.len() {
+        differences.push((firsts[i] - seconds[i]).abs());
+    }
+    differences
+}
+
+#[allow(unused_variables)]
+pub fn part1(lists: &(Vec<i32>, Vec<i32>)) -> String {
+    // sort both arrays
+    let mut firsts = lists.0.clone();
+    firsts.sort();
+    let mut seconds = lists.1.clone();
+    seconds.sort();
+    // get the differences of each pair of numbers between the two arrays
+    let differences = array_differences(&firsts, &seconds);
+    // get the sum of the differences
+    let sum: i32 = differences.iter().sum();
+    sum.to_string()
+}
+
+#[allow(unused_variables)]
+pub fn part2(lists: &(Vec<i32>, Vec<i32>)) -> String {
+    let (lefts, rights) = lists;
+
+    // create a hashmap for the number of times each number appears in the right array
+    let mut right_map = std::collections::HashMap::new();
+    for right in rights {
+        let count = right_map.entry(right).or_insert(0);
+        *count += 1;
+    }
+
+    // for each number N in the left array, get the number of times it appears in the right array and multiply it by N, and sum the results
+    let mut sum = 0;
+    for left in lefts {
+        let count = right_map.get(&left).unwrap_or(&0);
+        sum += left * count;
+    }
+
+    sum.to_string()
+}
+
+pub struct Day01;
+
+impl advent::Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(contents: &str) -> Result<Self::Input, advent::Error> {
+        get_arrays(contents)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(input)
+    }
+}
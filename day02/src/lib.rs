@@ -0,0 +1,88 @@
+// use the advent package
+use advent::parse;
+
+// each report is a line of numbers
+type Report = Vec<i32>;
+
+pub fn part1(reports: &Vec<Report>) -> String {
+    let safe = count_safe_reports(reports, &is_safe_report);
+    safe.to_string()
+}
+
+fn count_safe_reports(reports: &Vec<Report>, is_safe: &dyn Fn(&[i32]) -> bool) -> i32 {
+    let mut count = 0;
+    for report in reports {
+        if is_safe(report) {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn is_safe_report(numbers: &[i32]) -> bool {
+    // a report is safe if the numbers are either monotonically increasing or decreasing by between 1 and 3
+    let mut safe = true;
+    let mut sign = 0;
+    for i in 1..numbers.len() {
+        let diff = numbers[i] - numbers[i - 1];
+        if diff < 0 {
+            if sign > 0 {
+                safe = false;
+                break;
+            }
+            sign = -1;
+        } else if diff > 0 {
+            if sign < 0 {
+                safe = false;
+                break;
+            }
+            sign = 1;
+        } else {
+            safe = false;
+            break;
+        }
+        if diff.abs() < 1 || diff.abs() > 3 {
+            safe = false;
+            break;
+        }
+    }
+    safe
+}
+
+fn is_safe_report_with_dampener(report: &[i32]) -> bool {
+    // try removing each number once in the report. If any number is removed, the report is safe
+    let mut safe = false;
+    for i in 0..report.len() {
+        // remove the number at index i and consider the report safe if the modified report is safe
+        let mut modified_report = report.to_vec();
+        modified_report.remove(i);
+        if is_safe_report(&modified_report) {
+            safe = true;
+            break;
+        }
+    }
+    safe
+}
+
+pub fn part2(reports: &Vec<Report>) -> String {
+    let safe = count_safe_reports(reports, &is_safe_report_with_dampener);
+    safe.to_string()
+}
+
+pub struct Day02;
+
+impl advent::Solution for Day02 {
+    type Input = Vec<Report>;
+
+    fn parse(contents: &str) -> Result<Vec<Report>, advent::Error> {
+        advent::try_parse_lines(contents, parse::ints)
+    }
+
+    fn part1(input: &Vec<Report>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Report>) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,117 @@
+use regex::Regex;
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(contents: &str) -> String {
+    let instruction_args = get_instruction_args(contents);
+    let result = get_instruction_value(&instruction_args);
+    result.to_string()
+}
+
+fn get_instruction_value(instruction_args: &Vec<(i32, i32)>) -> i32 {
+    let mut count = 0;
+    for (a, b) in instruction_args {
+        count += a * b;
+    }
+    count
+}
+
+fn get_instruction_args(contents: &str) -> Vec<(i32, i32)> {
+    // contents is a single string. We are looking for all instances of the following regex:
+    // mul(\\d+,\\d+)
+    // where \\d+ is one or more digits
+    // the regex is looking for the string "mul" followed by an open parenthesis, one or more digits, a comma, one or more digits, and a close parenthesis
+    let valid_instruction = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+    // create a vector of pairs of integers
+    let mut instruction_args: Vec<(i32, i32)> = Vec::new();
+    for [arg1, arg2] in valid_instruction.captures_iter(contents).map(|cap| [cap.get(1).unwrap().as_str(), cap.get(2).unwrap().as_str()]) {
+        instruction_args.push((arg1.parse().unwrap(), arg2.parse().unwrap()));
+    }
+    instruction_args
+}
+
+#[allow(unused_variables)]
+pub fn part2(contents: &str) -> String {
+    let instruction_args = get_full_instruction_args(contents);
+    let result = get_full_instruction_value(&instruction_args);
+    result.to_string()
+}
+
+enum Instruction {
+    Do,
+    Dont,
+    Mul(i32, i32),
+}
+
+fn get_full_instruction_args(contents: &str) -> Vec<Instruction> {
+    let valid_instruction = Regex::new(r"(?<mul>mul)\((\d+),(\d+)\)|(?<do>do)\(\)|(?<dont>don't)\(\)").unwrap();
+    let mut instructions: Vec<Instruction> = Vec::new();
+    for instruction_capture in valid_instruction.captures_iter(contents) {
+        let instruction = instruction_capture.name("mul").or(instruction_capture.name("do")).or(instruction_capture.name("dont"));
+        let mut instruction_string = "";
+        match instruction {
+            None => {
+                println!("Invalid instruction");
+            },
+            Some(_) => {
+                instruction_string = instruction.unwrap().as_str();
+            },
+        }
+        match instruction_string {
+            "mul" => {
+                let arg1 = instruction_capture.get(2).unwrap().as_str().parse().unwrap();
+                let arg2 = instruction_capture.get(3).unwrap().as_str().parse().unwrap();
+                instructions.push(Instruction::Mul(arg1, arg2));
+            },
+            "do" => {
+                instructions.push(Instruction::Do);
+            },
+            "don\'t" => {
+                instructions.push(Instruction::Dont);
+            },
+            _ => {
+                println!("Invalid instruction: {}", instruction_string);
+            },
+        }
+    }
+    instructions
+}
+
+fn get_full_instruction_value(instruction_args: &Vec<Instruction>) -> i32 {
+    let mut count = 0;
+    let mut enable = true;
+    for instruction in instruction_args {
+        match instruction {
+            Instruction::Mul(a, b) => {
+                if enable {
+                    count += a * b;
+                }
+            },
+            Instruction::Do => {
+                enable = true;
+            },
+            Instruction::Dont => {
+                enable = false;
+            },
+        }
+    }
+    count
+}
+
+pub struct Day03;
+
+impl advent::Solution for Day03 {
+    type Input = String;
+
+    fn parse(contents: &str) -> Result<String, advent::Error> {
+        Ok(contents.to_string())
+    }
+
+    fn part1(input: &String) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &String) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,125 @@
+// use the advent package
+use advent::{Grid, Point2D};
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(xmas_map: &Grid<char>) -> String {
+    let xmas_count = count_xmas_in_map(xmas_map);
+    xmas_count.to_string()
+}
+
+fn count_xmas_in_map(xmas_map: &Grid<char>) -> i32 {
+    let mut xmas_count = 0;
+    for p in xmas_map.points() {
+        xmas_count += check_for_xmas_at_point(xmas_map, p);
+    }
+    xmas_count
+}
+
+// count the number of instances of 'XMAS' starting at point p going in any direction
+fn check_for_xmas_at_point(xmas_map: &Grid<char>, p: Point2D<i32>) -> i32 {
+    let mut xmas_count = 0;
+    // iterate through all the directions
+    for dy in -1..2 {
+        for dx in -1..2 {
+            // if dx and dy are both zero, we are at the current point
+            if dx == 0 && dy == 0 {
+                continue;
+            }
+            // check for 'XMAS' starting at point p going in direction (dx,dy)
+            if check_for_xmas_in_direction(xmas_map, p, Point2D::new(dx, dy)) {
+                xmas_count += 1;
+            }
+        }
+    }
+
+    xmas_count
+}
+
+// check for 'XMAS' starting at point p going in direction d
+fn check_for_xmas_in_direction(xmas_map: &Grid<char>, p: Point2D<i32>, d: Point2D<i32>) -> bool {
+    // if the direction is zero, we are not moving
+    if d.x == 0 && d.y == 0 {
+        return false;
+    }
+    // if the direction is not zero, we are moving
+    let mut p = p;
+    let xmas = String::from("XMAS");
+    // iterate through the characters in 'XMAS'
+    for c in xmas.chars() {
+        // if we are off the end of the map, or the character at p is not the character in 'XMAS', we are done
+        if xmas_map.get(p) != Some(&c) {
+            return false;
+        }
+        // move in the direction d
+        p += d;
+    }
+    // if we made it through the loop, we found 'XMAS'
+    true
+}
+
+#[allow(unused_variables)]
+pub fn part2(xmas_map: &Grid<char>) -> String {
+    let xmas_count = count_cross_mas_in_map(xmas_map);
+    xmas_count.to_string()
+}
+
+fn count_cross_mas_in_map(xmas_map: &Grid<char>) -> i32 {
+    let mut xmas_count = 0;
+    for p in xmas_map.points() {
+        if check_for_cross_mas_at_point(xmas_map, p) {
+            xmas_count += 1;
+        }
+    }
+    xmas_count
+}
+
+fn opposite_s_or_m(c: char) -> char {
+    if c == 'S' {
+        return 'M';
+    }
+    if c == 'M' {
+        return 'S';
+    }
+    c
+}
+
+fn check_for_cross_mas_at_point(xmas_map: &Grid<char>, p: Point2D<i32>) -> bool {
+    // a corner off the edge of the map can't be part of a cross
+    let corner = |dx: i32, dy: i32| *xmas_map.get(Point2D::new(p.x + dx, p.y + dy)).unwrap_or(&'.');
+    if xmas_map[p] == 'A' {
+        // check that both diagonals are 'MAS'
+        // check down right diagonal
+        if corner(1, 1) == 'S' || corner(1, 1) == 'M' {
+            // check up left diagonal
+            if corner(-1, -1) == opposite_s_or_m(corner(1, 1)) {
+                // check down left diagonal
+                if corner(-1, 1) == 'S' || corner(-1, 1) == 'M' {
+                    // check up right diagonal
+                    if corner(1, -1) == opposite_s_or_m(corner(-1, 1)) {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+pub struct Day04;
+
+impl advent::Solution for Day04 {
+    type Input = Grid<char>;
+
+    fn parse(contents: &str) -> Result<Grid<char>, advent::Error> {
+        Grid::parse_chars(contents)
+    }
+
+    fn part1(input: &Grid<char>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<char>) -> String {
+        part2(input)
+    }
+}
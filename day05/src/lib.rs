@@ -0,0 +1,83 @@
+// use the advent package
+use advent::{parse, Graph};
+
+// the ordering rules, and the page updates to check against them
+type PrintQueue = (Graph<i32>, Vec<Vec<i32>>);
+
+pub fn part1((rule_map, page_updates): &PrintQueue) -> String {
+    let result = process_page_updates(rule_map, page_updates);
+    result.to_string()
+}
+
+fn process_page_updates(rule_map: &Graph<i32>, page_updates: &Vec<Vec<i32>>) -> i32 {
+    let mut result = 0;
+    for page_update in page_updates {
+        result += is_valid_update(rule_map, page_update);
+    }
+    result
+}
+
+fn is_valid_update(rule_map: &Graph<i32>, page_update: &[i32]) -> i32 {
+    // for each page in the update, check if all subsequent pages are in the rule_map for that page value
+    for i in 0..page_update.len() - 1 {
+        let page = page_update[i];
+        let next_page = page_update[i + 1];
+        if !rule_map.has_edge(&page, &next_page) {
+            return 0;
+        }
+    }
+    // if we got this far, then the update is valid, and we return the middle value of the page update
+    page_update[page_update.len() / 2]
+}
+
+// the rules come first, where each rule a|b is an edge from page a to page b, which must come after it,
+// then the page updates, one per line
+fn parse_input(contents: &str) -> Result<PrintQueue, advent::Error> {
+    let rules = |rules: &str| advent::try_parse_lines(rules, |rule| parse::split_once_typed(rule, "|")).map(Graph::directed_from);
+    parse::sections(contents, rules, |updates| advent::try_parse_lines(updates, parse::ints))
+}
+
+pub fn part2((rule_map, page_updates): &PrintQueue) -> String {
+    let result = process_invalid_page_updates(rule_map, page_updates);
+    result.to_string()
+}
+
+fn process_invalid_page_updates(rule_map: &Graph<i32>, page_updates: &Vec<Vec<i32>>) -> i32 {
+    let mut result = 0;
+    for page_update in page_updates {
+        if is_valid_update(rule_map, page_update) == 0 {
+            let fixed_page_update = fix_page_update(rule_map, page_update);
+            result += fixed_page_update[fixed_page_update.len() / 2];
+        }
+    }
+    result
+}
+
+// create a type alias called Page for an integer (i32)
+type Page = i32;
+
+fn fix_page_update(rule_map: &Graph<i32>, page_update: &[i32]) -> Vec<Page> {
+    // the rules over the whole input can have cycles, but the ones between the pages
+    // of a single update don't, so those pages can be put in order with a topological sort
+    let pages = rule_map.subgraph(page_update.iter().copied());
+    pages.topological_sort().expect("the rules for an update shouldn't have a cycle")
+}
+
+
+pub struct Day05;
+
+impl advent::Solution for Day05 {
+    type Input = PrintQueue;
+
+    fn parse(contents: &str) -> Result<PrintQueue, advent::Error> {
+        parse_input(contents)
+    }
+
+    fn part1(input: &PrintQueue) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &PrintQueue) -> String {
+        part2(input)
+    }
+}
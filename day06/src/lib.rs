@@ -0,0 +1,121 @@
+// use the advent package
+use advent::{Direction, Grid, Point2D};
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(input: &Lab) -> String {
+    let (grid, guard_position, guard_direction) = input;
+    let (result, _) = track_guard_path(&mut grid.clone(), guard_position, guard_direction);
+    result.to_string()
+}
+
+type Position = Point2D<i32>;
+// the map with the guard taken off it, and where the guard starts and which way it's facing
+type Lab = (Grid<char>, Position, Direction);
+
+fn parse_input(contents: &str) -> Result<Lab, advent::Error> {
+    let mut grid = Grid::parse_chars(contents)?;
+    let (guard_position, guard_direction) = grid.iter()
+        .find_map(|(p, c)| Direction::from_arrow(*c).map(|direction| (p, direction)))
+        .ok_or_else(|| advent::Error::expected("a guard, one of ^ v < >"))?;
+    // replace the guard with an empty space
+    grid[guard_position] = '.';
+    Ok((grid, guard_position, guard_direction))
+}
+
+fn move_guard(grid: &Grid<char>, guard_position: &Position, guard_direction: &Direction) -> (Position, bool) {
+    let new_position = guard_position.step(*guard_direction);
+    let done = !grid.in_bounds(new_position);
+    (new_position, done)
+}
+
+fn turn_guard(guard_direction: &Direction) -> Direction {
+    guard_direction.turn_right()
+}
+
+fn track_guard_path(grid: &mut Grid<char>, guard_position: &Position, guard_direction: &Direction) -> (i32, Vec<Position>) {
+    let mut guard_position = *guard_position;
+    let mut guard_direction = *guard_direction;
+    let mut count = 1;
+    let mut done = false;
+    let mut new_position;
+    let mut path = Vec::new();
+    grid[guard_position] = 'X';
+    path.push(guard_position);
+    while !done {
+        (new_position, done) = move_guard(grid, &guard_position, &guard_direction);
+        if !done {
+            if grid[new_position] == '#' {
+                guard_direction = turn_guard(&guard_direction);
+            } else {
+                guard_position = new_position;
+                if grid[guard_position] != 'X' {
+                    grid[guard_position] = 'X';
+                    path.push(guard_position);
+                    count += 1;
+                }
+            }
+        }
+    }
+    (count, path)
+}
+
+#[allow(unused_variables)]
+pub fn part2(input: &Lab) -> String {
+    let mut result = 0;
+    let (grid, guard_position, guard_direction) = input;
+    let (_, path) = track_guard_path(&mut grid.clone(), guard_position, guard_direction);
+    // try adding an obstacle at each position on the grid that doesn't already have an obstacle
+    for position in path {
+        let mut grid = grid.clone();
+        add_obstacle_at(&mut grid, &position);
+        if has_loop(&grid, guard_position, guard_direction) {
+            result += 1;
+        }
+    }
+    result.to_string()
+}
+
+fn add_obstacle_at(grid: &mut Grid<char>, position: &Position) {
+    grid[*position] = '#';
+}
+
+type GuardState = (Position, Direction);
+
+// where the guard is after one more move, or None once the guard has walked off the map
+fn step_guard(grid: &Grid<char>, (guard_position, guard_direction): GuardState) -> Option<GuardState> {
+    let (new_position, done) = move_guard(grid, &guard_position, &guard_direction);
+    if done {
+        None
+    } else if grid[new_position] == '#' {
+        Some((guard_position, turn_guard(&guard_direction)))
+    } else {
+        Some((new_position, guard_direction))
+    }
+}
+
+fn has_loop(grid: &Grid<char>, guard_position: &Position, guard_direction: &Direction) -> bool {
+    // once off the map the guard stays off it, which is a cycle too, so the guard loops
+    // if the cycle the walk ends up in is on the map
+    let start = Some((*guard_position, *guard_direction));
+    let cycle = advent::find_cycle(start, |state| state.and_then(|state| step_guard(grid, state)));
+    cycle.first.is_some()
+}
+
+pub struct Day06;
+
+impl advent::Solution for Day06 {
+    type Input = Lab;
+
+    fn parse(contents: &str) -> Result<Lab, advent::Error> {
+        parse_input(contents)
+    }
+
+    fn part1(input: &Lab) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Lab) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,104 @@
+// use the advent package
+use advent::{parse, Memo};
+
+// the answer, and the operands that might make it
+type Equation = (i64, Vec<i64>);
+
+pub fn part1(equations: &Vec<Equation>) -> String {
+    let mut sum = 0;
+    for equation in equations {
+        sum += test_line(equation, false);
+    }
+    sum.to_string()
+}
+
+// each line is the answer, a colon, then the operands
+fn parse_equation(line: &str) -> Result<Equation, advent::Error> {
+    let numbers = parse::ints(line)?;
+    match numbers.split_first() {
+        Some((&answer, operands)) if !operands.is_empty() => Ok((answer, operands.to_vec())),
+        _ => Err(advent::Error::expected("answer: operands")),
+    }
+}
+
+fn test_line(&(answer, ref operands): &Equation, concatenate: bool) -> i64 {
+    // whether the first count operands can make a value, remembered as different operators reach the same value
+    let mut can_make = Memo::new(|memo, &(value, count): &(i64, usize)| check_answer(memo, operands, concatenate, value, count));
+    if can_make.get((answer, operands.len())) {
+        return answer;
+    }
+    0
+}
+
+// work backwards from the answer, undoing the last operator each way it could have been
+fn check_answer(memo: &mut Memo<(i64, usize), bool>, operands: &[i64], concatenate: bool, answer: i64, count: usize) -> bool {
+    if answer < 0 {
+        return false;
+    }
+    if count == 1 {
+        return operands[0] == answer;
+    }
+    let last = operands[count-1];
+    // a = (...) op b
+
+    // check if a = (...) + b, or a-b = (...)
+    if memo.get((answer - last, count-1)) {
+        return true;
+    }
+
+    // check if a = (...) * b, or a/b = (...), where anything times 0 is 0
+    if last == 0 && answer == 0 {
+        return true;
+    }
+    if (last != 0) && (answer % last == 0) && memo.get((answer / last, count-1)) {
+        return true;
+    }
+
+    // check if a = (...)b, or a\b = (...)
+    if concatenate {
+        let a_string = answer.to_string();
+        let b_string = last.to_string();
+        // check if b_string is a suffix of a_string
+        if (a_string.len() > b_string.len()) && (a_string.ends_with(&b_string)) {
+            let new_a = a_string[0..a_string.len()-b_string.len()].to_string();
+            let a = new_a.parse::<i64>();
+            match a {
+                Ok(a) => {
+                    if memo.get((a, count-1)) {
+                        return true;
+                    }
+                }
+                Err(_) => {
+                    println!("Error parsing {} after removing {} from {} with {:?} remaining", new_a, b_string, a_string, operands[0..count-1].to_vec());
+                }
+            }
+        }
+    }
+    false
+}
+
+pub fn part2(equations: &Vec<Equation>) -> String {
+    let mut sum = 0;
+    for equation in equations {
+        sum += test_line(equation, true);
+    }
+    sum.to_string()
+}
+
+pub struct Day07;
+
+impl advent::Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    fn parse(contents: &str) -> Result<Vec<Equation>, advent::Error> {
+        advent::try_parse_lines(contents, parse_equation)
+    }
+
+    fn part1(input: &Vec<Equation>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Equation>) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::{HashMap, HashSet};
+
+// use the advent package
+use advent::{Grid, Point2D};
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(grid: &Grid<char>) -> String {
+    let node_map = get_node_map(grid);
+    let antinodes = get_antinodes(grid, &node_map);
+    print_antinodes_on_grid(grid, &antinodes);
+    // get the count of antinodes
+    let result = antinodes.len();
+    result.to_string()
+}
+
+type Point = Point2D<i32>;
+type NodeMap = HashMap<char, Vec<Point>>;
+
+fn print_antinodes_on_grid(grid: &Grid<char>, antinodes: &HashSet<Point>) {
+    let grid = grid.map(|point, c| if antinodes.contains(&point) { '#' } else { *c });
+    println!("{}", grid);
+}
+
+fn get_antinodes(grid: &Grid<char>, node_map: &NodeMap) -> HashSet<Point> {
+    let mut antinodes = HashSet::new();
+    for (_node, points) in node_map.iter() {
+        for point1 in points {
+            for point2 in points {
+                if point1 == point2 {
+                    continue;
+                }
+                // get the direction from point1 to point2
+                let direction = *point2 - *point1;
+                // go further in that direction past point2
+                let antinode1 = *point2 + direction;
+                // go the negative direction past point1
+                let antinode2 = *point1 - direction;
+                // check if the antinodes are in bounds
+                if grid.in_bounds(antinode1) {
+                    antinodes.insert(antinode1);
+                }
+                if grid.in_bounds(antinode2) {
+                    antinodes.insert(antinode2);
+                }
+            }
+        }
+    }
+    antinodes
+}
+
+fn get_antinodes_with_harmonics(grid: &Grid<char>, node_map: &NodeMap) -> HashSet<Point> {
+    let mut antinodes = HashSet::new();
+    for (_node, points) in node_map.iter() {
+        if points.len() < 2 {
+            continue;
+        }
+        for point1 in points {
+            for point2 in points {
+                if point1 == point2 {
+                    continue;
+                }
+                antinodes.insert(*point1);
+                antinodes.insert(*point2);
+                // get the direction from point1 to point2
+                let direction = *point2 - *point1;
+                // go further in that direction past point2
+                let mut current_point = *point2;
+                loop {
+                    let antinode = current_point + direction;
+                    // check if the antinode is in bounds
+                    if !grid.in_bounds(antinode) {
+                        break;
+                    }
+                    current_point = antinode;
+                    antinodes.insert(antinode);
+                }
+                current_point = *point1;
+                loop {
+                    let antinode = current_point - direction;
+                    // check if the antinode is in bounds
+                    if !grid.in_bounds(antinode) {
+                        break;
+                    }
+                    current_point = antinode;
+                    antinodes.insert(antinode);
+                }
+            }
+        }
+    }
+    antinodes
+}
+
+fn get_node_map(grid: &Grid<char>) -> NodeMap {
+    let mut node_map: NodeMap = HashMap::new();
+    for (point, c) in grid.iter() {
+        match c {
+            '.' => (),
+            _ => {
+                // add the current point to the node map
+                node_map.entry(*c).or_default().push(point);
+            }
+        }
+    }
+    node_map
+}
+
+#[allow(unused_variables)]
+pub fn part2(grid: &Grid<char>) -> String {
+    let node_map = get_node_map(grid);
+    let antinodes = get_antinodes_with_harmonics(grid, &node_map);
+    print_antinodes_on_grid(grid, &antinodes);
+    // get the count of antinodes
+    let result = antinodes.len();
+    result.to_string()
+}
+
+pub struct Day08;
+
+impl advent::Solution for Day08 {
+    type Input = Grid<char>;
+
+    fn parse(contents: &str) -> Result<Grid<char>, advent::Error> {
+        Grid::parse_chars(contents)
+    }
+
+    fn part1(input: &Grid<char>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<char>) -> String {
+        part2(input)
+    }
+}
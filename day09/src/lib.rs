@@ -0,0 +1,172 @@
+// use the advent package
+use advent::{Interval, IntervalSet};
+
+pub fn part1(disk_map: &[i32]) -> String {
+    let (block_ids, block_counts, space_counts) = block_parser(disk_map);
+    let checksum = block_checksum(block_ids, &block_counts, &space_counts);
+    checksum.to_string()
+}
+
+// returns the following:
+// i32: number of block IDs.
+// Vec<i32>: the repeat count for each block ID.
+// Vec<i32>: the repeat count of space between each block ID.
+// The disk map alternates between block repeat counts and space repeat counts.
+fn block_parser(disk_map: &[i32]) -> (i32, Vec<i32>, Vec<i32>) {
+    let mut block_ids = 0;
+    let mut block_counts = Vec::new();
+    let mut space_counts = Vec::new();
+    let mut block_count = true;
+    for &count in disk_map {
+        if block_count {
+            block_ids += 1;
+            block_counts.push(count);
+        } else {
+            space_counts.push(count);
+        }
+        block_count = !block_count;
+    }
+    (block_ids, block_counts, space_counts)
+}
+
+fn block_checksum(block_count: i32, block_counts: &[i32], space_counts: &[i32]) -> u64 {
+    let (block_list_size, block_list) = get_block_list(block_count, block_counts, space_counts);
+    let mut checksum: u64 = 0;
+    let mut end = block_list_size - 1;
+    let mut compact_blocks = Vec::new();
+
+    for i in 0..block_list_size {
+        if i > end {
+            break;
+        }
+        if block_list[i as usize] >= 0 {
+            compact_blocks.push(block_list[i as usize]);
+            // print!("{}", block_list[i as usize]);
+        } else {
+            while block_list[end as usize] < 0 && end > i {
+                end -= 1;
+            }
+            // print!("{}", block_list[end as usize]);
+            compact_blocks.push(block_list[end as usize]);
+            end -= 1;
+        }
+    }
+    for (i,v) in compact_blocks.iter().enumerate() {
+        checksum += (*v as u64) * (i as u64);
+    }
+    println!();
+    checksum
+}
+
+#[derive(Clone, Copy)]
+struct Block {
+    block_id: i32,
+    start: i32,
+    end: i32,
+    count: i32,
+}
+
+fn file_block_checksum(block_count: i32, block_counts: &[i32], space_counts: &[i32]) -> u64 {
+    let (file_blocks, mut free_space) = get_file_block_list(block_count, block_counts, space_counts);
+    let mut checksum: u64 = 0;
+    let mut new_file_blocks = file_blocks.clone();
+    for i in (0..file_blocks.len()).rev() {
+        // the leftmost free space the whole file fits in, if it is left of the file
+        let space = free_space.iter()
+            .find(|space| space.len() >= file_blocks[i].count)
+            .filter(|space| space.start < file_blocks[i].start)
+            .copied();
+        if let Some(space) = space {
+            let mut new_file_block = file_blocks[i];
+            new_file_block.start = space.start;
+            new_file_block.end = new_file_block.start + new_file_block.count - 1;
+            free_space.remove(Interval::new(new_file_block.start, new_file_block.end + 1));
+            new_file_blocks[i] = new_file_block;
+        }
+    }
+    for b in new_file_blocks {
+        // get the sum of indexes from start to end
+        let start: u64 = b.start as u64;
+        let end: u64 = b.end as u64;
+        let sum = (start+end)*(end-start+1)/2;
+        checksum += (b.block_id as u64) * sum;
+    }
+    println!();
+    checksum
+}
+
+// returns the file blocks and the free space between them
+fn get_file_block_list(block_count: i32, block_counts: &[i32], space_counts: &[i32]) -> (Vec<Block>, IntervalSet<i32>) {
+    let mut file_blocks = Vec::new();
+    let mut free_space = IntervalSet::new();
+    let mut offset = 0;
+    let mut index = 0;
+    for i in 0..block_count-1 {
+        file_blocks.push(Block{block_id: index, start: offset, end: offset + block_counts[i as usize]-1, count: block_counts[i as usize]});
+        offset += block_counts[i as usize];
+        free_space.insert(Interval::new(offset, offset + space_counts[i as usize]));
+        offset += space_counts[i as usize];
+        index += 1;
+    }
+    file_blocks.push(Block{block_id: index, start: offset, end: index + block_counts[(block_count-1) as usize]-1, count: block_counts[(block_count-1) as usize]});
+    (file_blocks, free_space)
+}
+
+fn get_block_list(block_count: i32, block_counts: &[i32], space_counts: &[i32]) -> (i32, Vec<i32>) {
+    let mut block_list = Vec::new();
+    let mut index = 0;
+    for i in 0..block_count-1 {
+        for _ in 0..block_counts[i as usize] {
+            block_list.push(i);
+            index += 1;
+        }
+        for _ in 0..space_counts[i as usize] {
+            block_list.push(-1);
+            index += 1;
+        }
+    }
+    for _ in 0..block_counts[(block_count-1) as usize] {
+        block_list.push(block_count-1);
+        index += 1;
+    }
+    (index, block_list)
+}
+
+// the disk map is a line of single digit counts, and has at least one file in it
+fn parse_disk_map(contents: &str) -> Result<Vec<i32>, advent::Error> {
+    let lines = advent::try_parse_lines(contents, |line| {
+        line.chars()
+            .enumerate()
+            .map(|(i, c)| c.to_digit(10).map(|d| d as i32).ok_or_else(|| advent::Error::expected("a count from 0 to 9").at_column(i + 1)))
+            .collect::<Result<Vec<i32>, _>>()
+    })?;
+    let disk_map = lines.concat();
+    if disk_map.is_empty() {
+        return Err(advent::Error::expected("a disk map"));
+    }
+    Ok(disk_map)
+}
+
+pub fn part2(disk_map: &[i32]) -> String {
+    let (block_ids, block_counts, space_counts) = block_parser(disk_map);
+    let checksum = file_block_checksum(block_ids, &block_counts, &space_counts);
+    checksum.to_string()
+}
+
+pub struct Day09;
+
+impl advent::Solution for Day09 {
+    type Input = Vec<i32>;
+
+    fn parse(contents: &str) -> Result<Vec<i32>, advent::Error> {
+        parse_disk_map(contents)
+    }
+
+    fn part1(input: &Vec<i32>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<i32>) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,61 @@
+// use the advent package
+use advent::{Grid, Point2D};
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(grid: &Grid<i32>) -> String {
+    let mut count = 0;
+    for start in grid.find_all(&0) {
+        // the peaks that can be reached by always climbing one step up
+        let reachable = grid.bfs(start, |&height, &next| next == height + 1);
+        count += reachable.keys().filter(|p| grid[**p] == 9).count();
+    }
+    count.to_string()
+}
+
+type Point = Point2D<i32>;
+
+// the number of different trails from pos up to a peak
+fn good_trails_from_here(grid: &Grid<i32>, pos: Point) -> i32 {
+    if grid[pos] == 9 {
+        return 1;
+    }
+    grid.neighbors4(pos)
+        .filter(|next| grid[*next] == grid[pos] + 1)
+        .map(|next| good_trails_from_here(grid, next))
+        .sum()
+}
+
+fn parse_input(contents: &str) -> Result<Grid<i32>, advent::Error> {
+    // each cell is the height as a single digit
+    Grid::parse(contents, |c| {
+        c.to_digit(10).map(|d| d as i32).ok_or_else(|| advent::Error::expected("a height from 0 to 9"))
+    })
+}
+
+#[allow(unused_variables)]
+pub fn part2(grid: &Grid<i32>) -> String {
+    let mut count = 0;
+    for start in grid.find_all(&0) {
+        count += good_trails_from_here(grid, start);
+    }
+    count.to_string()
+}
+
+pub struct Day10;
+
+impl advent::Solution for Day10 {
+    type Input = Grid<i32>;
+
+    fn parse(contents: &str) -> Result<Grid<i32>, advent::Error> {
+        parse_input(contents)
+    }
+
+    fn part1(input: &Grid<i32>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<i32>) -> String {
+        part2(input)
+    }
+}
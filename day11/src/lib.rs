@@ -0,0 +1,89 @@
+// use the advent package
+use advent::Memo;
+
+pub fn part1(stones: &[u64]) -> String {
+    let mut new_stones = stones.to_vec();
+    // print_stones(&new_stones);
+    for _ in 0..25 {
+        new_stones = blink(new_stones);
+        // print_stones(&new_stones);
+    }
+    new_stones.len().to_string()
+}
+
+#[allow(dead_code)]
+fn print_stones(stones: &[u64]) {
+    for stone in stones.iter() {
+        print!("({}) ", stone);
+    }
+    println!();
+    println!();
+}
+
+fn blink(stones: Vec<u64>) -> Vec<u64> {
+    let mut new_stones = Vec::new();
+    for stone in stones {
+        let digits = stone.checked_ilog10().unwrap_or(0) + 1;
+        if stone == 0 {
+            new_stones.push(1);
+        } else if digits % 2 == 0 {
+            // split the digits into two halves
+            let half = 10u64.pow(digits / 2);
+            new_stones.push(stone / half);
+            new_stones.push(stone % half);
+        } else {
+            new_stones.push(stone * 2024);
+        }
+    }
+
+    new_stones
+}
+
+// the numbers engraved on the stones, separated by spaces
+fn parse_stones(contents: &str) -> Result<Vec<u64>, advent::Error> {
+    Ok(advent::try_parse_lines(contents, advent::try_split_line_into)?.concat())
+}
+
+pub fn part2(stones: &[u64]) -> String {
+    let mut stone_count = Memo::new(count_stones);
+    let mut total: u64 = 0;
+    for &stone in stones {
+        total += stone_count.get((stone, 75));
+    }
+    total.to_string()
+}
+
+// the number of stones one stone turns into after some blinks, which only depends on its number,
+// so each (stone, blinks) pair is counted once however many stones share it
+fn count_stones(memo: &mut Memo<(u64, u32), u64>, &(stone, blinks): &(u64, u32)) -> u64 {
+    if blinks == 0 {
+        return 1;
+    }
+    let digits = stone.checked_ilog10().unwrap_or(0) + 1;
+    if stone == 0 {
+        memo.get((1, blinks - 1))
+    } else if digits % 2 == 0 {
+        let half = 10u64.pow(digits / 2);
+        memo.get((stone / half, blinks - 1)) + memo.get((stone % half, blinks - 1))
+    } else {
+        memo.get((stone * 2024, blinks - 1))
+    }
+}
+
+pub struct Day11;
+
+impl advent::Solution for Day11 {
+    type Input = Vec<u64>;
+
+    fn parse(contents: &str) -> Result<Vec<u64>, advent::Error> {
+        parse_stones(contents)
+    }
+
+    fn part1(input: &Vec<u64>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<u64>) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+// use the advent package
+use advent::{Grid, Point2D};
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(garden: &Garden) -> String {
+    let mut cost = 0;
+    for region in garden.regions(|a, b| a.plant == b.plant) {
+        cost += region_cost(garden, &region);
+    }
+    cost.to_string()
+}
+
+type Point = Point2D<i32>;
+
+fn region_cost(garden: &Garden, region: &HashSet<Point>) -> i32 {
+    // every side of a plot that doesn't face the same plant is fence
+    let perimeter: i32 = region.iter().map(|p| 4 - garden[*p].neighbors).sum();
+    region.len() as i32 * perimeter
+}
+
+#[derive(Clone)]
+pub struct Plot {
+    plant: char,
+    neighbors: i32
+}
+
+type Garden = Grid<Plot>;
+
+fn make_garden(contents: &str) -> Result<Garden, advent::Error> {
+    let plants = Grid::parse_chars(contents)?;
+    // count neighbors that have the same plant
+    let garden = plants.map(|p, plant| {
+        let neighbors = plants.neighbors4(p).filter(|n| plants[*n] == *plant).count() as i32;
+        Plot { plant: *plant, neighbors }
+    });
+    Ok(garden)
+}
+
+#[allow(unused_variables)]
+pub fn part2(garden: &Garden) -> String {
+    let mut cost = 0;
+    for region in garden.regions(|a, b| a.plant == b.plant) {
+        cost += discount_cost(garden, &region);
+    }
+    cost.to_string()
+}
+
+fn discount_cost(garden: &Garden, one_block: &HashSet<Point>) -> i32 {
+    let minx = one_block.iter().map(|p| p.x).min().unwrap();
+    let miny = one_block.iter().map(|p| p.y).min().unwrap();
+    let maxx = one_block.iter().map(|p| p.x).max().unwrap();
+    let maxy = one_block.iter().map(|p| p.y).max().unwrap();
+
+    let (count, sides) = find_sides(garden, minx, miny, maxx, maxy, one_block);
+
+    count * sides
+}
+
+fn find_sides(garden: &Garden, minx: i32, miny: i32, maxx: i32, maxy: i32, one_block: &HashSet<Point>) -> (i32, i32) {
+    let mut sides = 0;
+    let count = one_block.len() as i32;
+    // a plot of this region with a border on the given side
+    let border = |x: i32, y: i32, dx: i32, dy: i32| {
+        one_block.contains(&Point::new(x, y)) && has_border(garden, Point::new(x, y), dx, dy)
+    };
+
+    // top sides
+    for y in miny..=maxy {
+        let mut x = minx;
+        while x <= maxx {
+            if border(x, y, 0, -1) {
+                sides += 1;
+                x += 1;
+                while x <= maxx && border(x, y, 0, -1) {
+                    x += 1;
+                }
+            } else {
+                x += 1;
+            }
+        }
+    }
+
+    // bottom sides
+    for y in miny..=maxy {
+        let mut x = minx;
+        while x <= maxx {
+            if border(x, y, 0, 1) {
+                sides += 1;
+                x += 1;
+                while x <= maxx && border(x, y, 0, 1) {
+                    x += 1;
+                }
+            } else {
+                x += 1;
+            }
+        }
+    }
+
+    // left sides
+    for x in minx..=maxx {
+        let mut y = miny;
+        while y<= maxy {
+            if border(x, y, -1, 0) {
+                sides += 1;
+                y += 1;
+                while y <= maxy && border(x, y, -1, 0) {
+                    y += 1;
+                }
+            } else {
+                y += 1;
+            }
+        }
+    }
+
+    // right sides
+    for x in minx..=maxx {
+        let mut y = miny;
+        while y <= maxy {
+            if border(x, y, 1, 0) {
+                sides += 1;
+                y += 1;
+                while y <= maxy && border(x, y, 1, 0) {
+                    y += 1;
+                }
+            } else {
+                y += 1;
+            }
+        }
+    }
+
+    (count, sides)
+}
+
+fn has_border(grid: &Garden, p: Point, dx: i32, dy: i32) -> bool {
+    // the edge of the garden is a border too
+    match grid.get(Point::new(p.x + dx, p.y + dy)) {
+        Some(neighbor) => neighbor.plant != grid[p].plant,
+        None => true,
+    }
+}
+
+pub struct Day12;
+
+impl advent::Solution for Day12 {
+    type Input = Grid<Plot>;
+
+    fn parse(contents: &str) -> Result<Grid<Plot>, advent::Error> {
+        make_garden(contents)
+    }
+
+    fn part1(input: &Grid<Plot>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<Plot>) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,126 @@
+// use the advent package
+use advent::parse;
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(machines: &Vec<Machine>) -> String {
+    let mut tokens = 0;
+    for machine in machines {
+        if let Ok((na,nb)) = win_prize(machine) {
+            tokens += 3 * na + nb;
+        }
+    }
+
+    tokens.to_string()
+}
+
+pub struct Machine {
+    a: (i128,i128),
+    b: (i128,i128),
+    p: (i128,i128),
+}
+
+fn win_prize(m: &Machine) -> Result<(i128,i128),&str> {
+    // figure out how many times to press button A and button B to reach the prize location
+    // the prize location is at m.p
+    // the location starts at (0,0)
+    // the location is updated by pressing button A or button B, which moves the location by the corresponding amount
+    // if na is the number of times button A is pressed and nb is the number of times button B is pressed, then
+    // the location is updated by (na * m.a.0 + nb * m.b.0, na * m.a.1 + nb * m.b.1)
+    // na * m.a.0 + nb * m.b.0 = m.p.0
+    // na * m.a.1 + nb * m.b.1 = m.p.1
+    // solve for na and nb
+    // na = (m.p.0 * m.b.1 - m.p.1 * m.b.0) / (m.a.0 * m.b.1 - m.a.1 * m.b.0)
+    // nb = (m.p.1 * m.a.0 - m.p.0 * m.a.1) / (m.a.0 * m.b.1 - m.a.1 * m.b.0)
+    // if na and nb are both positive integers, then the prize can be won
+    // if na and nb are not both positive integers, then the prize cannot be won
+    let na = (m.p.0 * m.b.1 - m.p.1 * m.b.0) / (m.a.0 * m.b.1 - m.a.1 * m.b.0);
+    let nar = (m.p.0 * m.b.1 - m.p.1 * m.b.0) % (m.a.0 * m.b.1 - m.a.1 * m.b.0);
+    let nb = (m.p.1 * m.a.0 - m.p.0 * m.a.1) / (m.a.0 * m.b.1 - m.a.1 * m.b.0);
+    let nbr = (m.p.1 * m.a.0 - m.p.0 * m.a.1) % (m.a.0 * m.b.1 - m.a.1 * m.b.0);
+    if na > 0 && nb > 0 && na <= 100 && nb <= 100 && nar == 0 && nbr == 0 {
+        Ok((na,nb))
+    } else {
+        Err("Cannot win prize")
+    }
+}
+
+fn win_prize2(m: &Machine) -> Result<(i128,i128),&str> {
+    // figure out how many times to press button A and button B to reach the prize location
+    // the prize location is at m.p
+    // the location starts at (0,0)
+    // the location is updated by pressing button A or button B, which moves the location by the corresponding amount
+    // if na is the number of times button A is pressed and nb is the number of times button B is pressed, then
+    // the location is updated by (na * m.a.0 + nb * m.b.0, na * m.a.1 + nb * m.b.1)
+    // na * m.a.0 + nb * m.b.0 = m.p.0
+    // na * m.a.1 + nb * m.b.1 = m.p.1
+    // solve for na and nb
+    // na = (m.p.0 * m.b.1 - m.p.1 * m.b.0) / (m.a.0 * m.b.1 - m.a.1 * m.b.0)
+    // nb = (m.p.1 * m.a.0 - m.p.0 * m.a.1) / (m.a.0 * m.b.1 - m.a.1 * m.b.0)
+    // if na and nb are both positive integers, then the prize can be won
+    // if na and nb are not both positive integers, then the prize cannot be won
+    let m = Machine {a: m.a, b: m.b, p: (m.p.0+10_000_000_000_000, m.p.1+10_000_000_000_000)};
+    let na = (m.p.0 * m.b.1 - m.p.1 * m.b.0) / (m.a.0 * m.b.1 - m.a.1 * m.b.0);
+    let nar = (m.p.0 * m.b.1 - m.p.1 * m.b.0) % (m.a.0 * m.b.1 - m.a.1 * m.b.0);
+    let nb = (m.p.1 * m.a.0 - m.p.0 * m.a.1) / (m.a.0 * m.b.1 - m.a.1 * m.b.0);
+    let nbr = (m.p.1 * m.a.0 - m.p.0 * m.a.1) % (m.a.0 * m.b.1 - m.a.1 * m.b.0);
+    if na > 0 && nb > 0 && nar == 0 && nbr == 0 {
+        Ok((na,nb))
+    } else {
+        Err("Cannot win prize")
+    }
+}
+
+fn get_machines(contents: &str) -> Result<Vec<Machine>, advent::Error> {
+    advent::split_input_into_numbered_sections(contents.trim_end())
+        .iter()
+        .map(|(first_line, section)| get_machine(section).map_err(|e| e.offset_line(*first_line)))
+        .collect()
+}
+
+fn get_machine(machine_section: &str) -> Result<Machine, advent::Error> {
+    // the string is of the form:
+    // Button A: X+94, Y+34
+    // Button B: X+22, Y+67
+    // Prize: X=8400, Y=5400
+    let mut lines = machine_section.lines();
+    let a = get_pair(lines.next(), "Button A: X+{}, Y+{}").map_err(|e| e.at_line(1))?;
+    let b = get_pair(lines.next(), "Button B: X+{}, Y+{}").map_err(|e| e.at_line(2))?;
+    let p = get_pair(lines.next(), "Prize: X={}, Y={}").map_err(|e| e.at_line(3))?;
+    Ok(Machine {a,b,p})
+}
+
+// match one line of a machine against its pattern, and get the two numbers in it
+fn get_pair(line: Option<&str>, pattern: &str) -> Result<(i128,i128), advent::Error> {
+    parse::scan(pattern, line.ok_or_else(|| advent::Error::expected(pattern))?)
+}
+
+#[allow(unused_variables)]
+pub fn part2(machines: &Vec<Machine>) -> String {
+    let mut tokens = 0;
+    for machine in machines {
+        if let Ok((na,nb)) = win_prize2(machine) {
+            tokens += 3 * na + nb;
+        }
+    }
+
+    tokens.to_string()
+}
+
+pub struct Day13;
+
+impl advent::Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(contents: &str) -> Result<Vec<Machine>, advent::Error> {
+        get_machines(contents)
+    }
+
+    fn part1(input: &Vec<Machine>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Machine>) -> String {
+        part2(input)
+    }
+}
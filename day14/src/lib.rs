@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+// use the advent package
+use advent::{parse, Grid, Point2D};
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(robots: &[Robot],space: Point2D<i32>) -> String {
+    let mut robots = robots.to_vec();
+    for robot in robots.iter_mut() {
+        // the robots wrap around the edges of the space
+        robot.p = (robot.p + robot.v*100).rem_euclid(&space);
+    }
+    let sf = safety_factor(&robots, space);
+    sf.to_string()
+}
+
+#[derive(Clone)]
+pub struct Robot {
+    p: Point2D<i32>,
+    v: Point2D<i32>
+}
+
+fn safety_factor(robots: &[Robot], space: Point2D<i32>) -> i32 {
+    let mut quadrants = [0, 0, 0, 0];
+    // quadrants
+    // 0 = top left
+    // 1 = top right
+    // 2 = bottom left
+    // 3 = bottom right
+    for robot in robots.iter() {
+        if robot.p.x < space.x/2 {
+            if robot.p.y < space.y/2 {
+                quadrants[0] += 1;
+            } else if robot.p.y > space.y/2 {
+                quadrants[2] += 1;
+            }
+        } else if robot.p.x > space.x/2 {
+            if robot.p.y < space.y/2 {
+                quadrants[1] += 1;
+            } else if robot.p.y > space.y/2 {
+                quadrants[3] += 1;
+            }
+        }
+    }
+    quadrants.iter().product::<i32>()
+}
+
+// the robots move in a 101x103 space, unless the input starts with another size, as width,height,
+// in a section of its own (the example's is 11,7)
+fn parse_space(line: &str) -> Result<Point2D<i32>, advent::Error> {
+    let [width, height] = parse::ints_array(line)?;
+    Ok(Point2D::new(width, height))
+}
+
+fn line_to_robot(line: &str) -> Result<Robot, advent::Error> {
+    // example input: p=0,4 v=3,-3
+    let (px, py, vx, vy) = parse::scan("p={},{} v={},{}", line)?;
+    Ok(Robot { p: Point2D::new(px, py), v: Point2D::new(vx, vy) })
+}
+
+#[allow(unused_variables)]
+pub fn part2(robots: &[Robot],space: Point2D<i32>) -> String {
+    let velocities: Vec<Point2D<i32>> = robots.iter().map(|robot| robot.v).collect();
+    // every robot moves the same way each second, so the positions of all the robots repeat
+    let step = |positions: &Vec<Point2D<i32>>| -> Vec<Point2D<i32>> {
+        positions.iter().zip(&velocities).map(|(p, v)| (*p + *v).rem_euclid(&space)).collect()
+    };
+    let start: Vec<Point2D<i32>> = robots.iter().map(|robot| robot.p).collect();
+    let cycle = advent::find_cycle(start.clone(), step);
+    // the picture is the only arrangement in a cycle where no two robots share a spot
+    let picture = std::iter::successors(Some(start), |positions| Some(step(positions)))
+        .take(cycle.mu + cycle.lambda)
+        .enumerate()
+        .find(|(_, positions)| positions.iter().collect::<HashSet<_>>().len() == positions.len());
+    let Some((seconds, positions)) = picture else {
+        return "No picture found".to_string();
+    };
+    let robots: Vec<Robot> = positions.into_iter().zip(velocities).map(|(p, v)| Robot { p, v }).collect();
+    show_robots(&robots, space);
+    seconds.to_string()
+}
+
+// show the robots as # on a background of dots
+fn show_robots(robots: &[Robot], space: Point2D<i32>) {
+    let mut grid = Grid::new(space.x, space.y, '.');
+    for robot in robots.iter() {
+        grid[robot.p] = '#';
+    }
+    let mut visualizer = advent::visualizer("day14-robots");
+    visualizer.grid(&grid);
+    visualizer.tick();
+}
+
+pub struct Day14;
+
+impl advent::Solution for Day14 {
+    type Input = (Vec<Robot>, Point2D<i32>);
+
+    fn parse(contents: &str) -> Result<Self::Input, advent::Error> {
+        let (space, robots) = parse::with_settings(contents, Point2D::new(101,103), parse_space, |lines| advent::try_parse_lines(lines, line_to_robot))?;
+        Ok((robots, space))
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(&input.0, input.1)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(&input.0, input.1)
+    }
+}
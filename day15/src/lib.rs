@@ -0,0 +1,153 @@
+// use the advent package
+use advent::{Direction, Grid, Point2D};
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(input: &(Grid<char>, Vec<Direction>)) -> String {
+    let (grid_input, moves) = input;
+    let mut grid = make_grid(grid_input,1);
+    simulate(&mut grid, moves.clone());
+    score_grid(&grid).to_string()
+}
+
+type Point = Point2D<i32>;
+
+struct Warehouse {
+    grid: Grid<char>,
+    robot: Point,
+}
+
+fn move_object(grid: &mut Warehouse, p: Point, m: Direction) -> bool {
+    if grid.grid[p] == '#' {
+        return false;
+    }
+    if grid.grid[p] == '.' {
+        return true;
+    }
+    let next_p = p.step(m);
+    // pushing a wide box up or down also pushes whatever is in front of its other half
+    let other_half = match (m, grid.grid[next_p]) {
+        (Direction::North | Direction::South, '[') => Some(next_p.step(Direction::East)),
+        (Direction::North | Direction::South, ']') => Some(next_p.step(Direction::West)),
+        _ => None,
+    };
+    let moved = move_object(grid, next_p, m) && other_half.is_none_or(|half| move_object(grid, half, m));
+    if moved {
+        if grid.grid[p] == '@' {
+            grid.robot = next_p;
+        }
+        grid.grid[next_p] = grid.grid[p];
+        grid.grid[p] = '.';
+    }
+    moved
+}
+
+fn can_move_object(grid: &Warehouse, p: Point, m: Direction) -> bool {
+    if grid.grid[p] == '#' {
+        return false;
+    }
+    if grid.grid[p] == '.' {
+        return true;
+    }
+    let next_p = p.step(m);
+    match (m, grid.grid[next_p]) {
+        // we have to check this plus the next to the right
+        (Direction::North | Direction::South, '[') => can_move_object(grid, next_p, m) && can_move_object(grid, next_p.step(Direction::East), m),
+        // we have to check this plus the next to the left
+        (Direction::North | Direction::South, ']') => can_move_object(grid, next_p, m) && can_move_object(grid, next_p.step(Direction::West), m),
+        _ => can_move_object(grid, next_p, m),
+    }
+}
+
+fn simulate(grid: &mut Warehouse, moves: Vec<Direction>) {
+    for m in moves {
+        // print_grid(grid);
+        if can_move_object(grid, grid.robot, m) {
+            move_object(grid, grid.robot, m);
+        }
+    }
+    print_grid(grid);
+}
+
+fn print_grid(grid: &Warehouse) {
+    println!("{}", grid.grid);
+}
+
+fn score_grid(grid: &Warehouse) -> i32 {
+    let mut sum = 0;
+    for (p, c) in grid.grid.iter() {
+        if *c == 'O' || *c == '[' {
+            sum += (p.y * 100) + p.x;
+        }
+    }
+    sum
+}
+
+fn make_grid(grid_input: &Grid<char>, factor: i32) -> Warehouse {
+    let mut grid = Warehouse {
+        grid: Grid::new(grid_input.width() * factor, grid_input.height(), '.'),
+        robot: Point::new(0, 0),
+    };
+    for (p, c) in grid_input.iter() {
+        let wide_p = Point::new(factor * p.x, p.y);
+        let right = Point::new(wide_p.x + 1, wide_p.y);
+        if *c == '@' {
+            grid.robot = wide_p;
+            grid.grid[wide_p] = '@';
+        } else if *c == 'O' {
+            if factor == 1 {
+                grid.grid[wide_p] = 'O';
+            } else {
+                grid.grid[wide_p] = '[';
+                grid.grid[right] = ']';
+            }
+        } else {
+            for i in 0..factor {
+                grid.grid[Point::new(wide_p.x + i, wide_p.y)] = *c;
+            }
+        }
+    }
+    grid
+}
+
+fn parse_input(contents: &str) -> Result<(Grid<char>, Vec<Direction>), advent::Error> {
+    let sections = advent::try_split_input_into_sections(contents, 2)?;
+    let grid = Grid::parse_chars(&sections[0])?;
+    if grid.find(&'@').is_none() {
+        return Err(advent::Error::expected("a robot @ in the warehouse").at_line(1));
+    }
+    // the moves are arrows, wrapped over several lines
+    let first_move_line = grid.height() as usize + 2;
+    let moves = advent::try_parse_lines(&sections[1], |line| {
+        line.chars().enumerate().map(|(x, c)| {
+            Direction::from_arrow(c).ok_or_else(|| advent::Error::expected("a move, one of ^ > v <").at_column(x + 1))
+        }).collect::<Result<Vec<Direction>, advent::Error>>()
+    }).map_err(|e| e.offset_line(first_move_line))?;
+    Ok((grid, moves.concat()))
+}
+
+#[allow(unused_variables)]
+pub fn part2(input: &(Grid<char>, Vec<Direction>)) -> String {
+    let (grid_input, moves) = input;
+    let mut grid = make_grid(grid_input,2);
+    simulate(&mut grid, moves.clone());
+    score_grid(&grid).to_string()
+}
+
+pub struct Day15;
+
+impl advent::Solution for Day15 {
+    type Input = (Grid<char>, Vec<Direction>);
+
+    fn parse(contents: &str) -> Result<(Grid<char>, Vec<Direction>), advent::Error> {
+        parse_input(contents)
+    }
+
+    fn part1(input: &(Grid<char>, Vec<Direction>)) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &(Grid<char>, Vec<Direction>)) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,112 @@
+
+// use the advent package
+use advent::{Direction, Grid, Point2D, SearchProblem};
+use std::collections::HashSet;
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(maze: &Maze) -> String {
+    let mut visualizer = advent::visualizer("day16-maze");
+    visualizer.grid(&maze.map);
+    // the same search as maze.shortest_path(), but showing each node as it is visited
+    let path = advent::Search::new(maze.start(), |node: &Node| maze.successors(node.clone()))
+        .on_visit(|node: &Node| {
+            visualizer.visited(node.pos);
+            visualizer.tick();
+        })
+        .to_any(|node| maze.is_goal(node));
+    let Some(path) = path else {
+        return "No path found".to_string();
+    };
+    visualizer.path(&path.nodes.iter().map(|node| node.pos).collect::<Vec<_>>());
+    visualizer.tick();
+    path.cost.to_string()
+}
+
+// direction is None once the reindeer is at the end, when it doesn't matter which way it faces
+#[derive(Clone,Eq,PartialEq,Hash,Debug)]
+pub struct Node {
+    pos: Point2D<i32>,
+    direction: Option<Direction>
+}
+
+// the maze, with where the reindeer starts and where it has to get to
+pub struct Maze {
+    map: Grid<char>,
+    start: Point2D<i32>,
+    end: Point2D<i32>,
+}
+
+impl SearchProblem for Maze {
+    type Node = Node;
+    type Cost = u64;
+
+    // the reindeer starts facing east
+    fn start(&self) -> Node {
+        Node { pos: self.start, direction: Some(Direction::East) }
+    }
+
+    // The moves from a node, and what they cost:
+    // 1 to step forward, if it is not into a wall
+    // 1000 for each quarter turn on the spot, if there is no wall that way (turning to face a wall is never useful)
+    // stepping onto the end also reaches the end node, which has no moves of its own
+    fn successors(&self, node: Node) -> impl IntoIterator<Item = (Node, u64)> {
+        let map = &self.map;
+        let pos = node.pos;
+        let facing = node.direction;
+        Direction::CARDINAL.into_iter()
+            .filter_map(move |direction| facing.filter(|f| direction != f.reverse()).map(|f| (f, direction)))
+            .flat_map(move |(facing, direction)| {
+                let next = pos.step(direction);
+                let open = map.get(next).is_some_and(|c| *c != '#');
+                let turn = (direction != facing && open)
+                    .then(|| (Node { pos, direction: Some(direction) }, 1000 * facing.quarter_turns_to(direction) as u64));
+                let end = (direction == facing && map.get(next) == Some(&'E'))
+                    .then_some((Node { pos: next, direction: None }, 1));
+                let forward = (direction == facing && open)
+                    .then_some((Node { pos: next, direction: Some(direction) }, 1));
+                turn.into_iter().chain(end).chain(forward)
+            })
+    }
+
+    fn is_goal(&self, node: &Node) -> bool {
+        node.pos == self.end && node.direction.is_none()
+    }
+}
+
+fn make_map(contents: &str) -> Result<Maze, advent::Error> {
+    let map = Grid::parse_chars(contents)?;
+    let start = map.find(&'S').ok_or_else(|| advent::Error::expected("a start S in the maze"))?;
+    let end = map.find(&'E').ok_or_else(|| advent::Error::expected("an end E in the maze"))?;
+    Ok(Maze { map, start, end })
+}
+
+#[allow(unused_variables)]
+pub fn part2(maze: &Maze) -> String {
+    let paths = maze.all_shortest_paths();
+    let Some(paths) = paths else {
+        return "No path found".to_string();
+    };
+    // the tiles on any best path, whichever way the reindeer faces on them
+    let tiles: HashSet<Point2D<i32>> = paths.nodes().iter().map(|node| node.pos).collect();
+    tiles.len().to_string()
+}
+
+
+pub struct Day16;
+
+impl advent::Solution for Day16 {
+    type Input = Maze;
+
+    fn parse(contents: &str) -> Result<Maze, advent::Error> {
+        make_map(contents)
+    }
+
+    fn part1(input: &Maze) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Maze) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,214 @@
+// use the advent package
+use advent::parse;
+
+#[derive(Clone)]
+pub struct Machine {
+    a: u128,
+    b: u128,
+    c: u128,
+    program: Vec<u128>,
+    op: usize,
+    output: Vec<u128>,
+    check: bool,
+}
+
+fn make_machine(contents: &str) -> Result<Machine, advent::Error> {
+    // the values of registers A, B and C, then after a blank line the program, a comma separated list of numbers
+    let ([a, b, c], program) = parse::sections(contents, parse::ints_array::<u128, 3>, parse::ints)?;
+    let machine = Machine {
+        a,
+        b,
+        c,
+        program,
+        op: 0,
+        check: false,
+        output: Vec::new(),
+    };
+    Ok(machine)
+}
+
+// define the operation functions on a Machine
+impl Machine {
+    #[allow(non_snake_case)]
+    fn AdivideOp(&mut self) {
+        self.a >>= self.Op();
+        self.op += 2;
+    }
+
+    #[allow(non_snake_case)]
+    fn BxorLit(&mut self) {
+        self.b ^= self.program[self.op+1];
+        self.op += 2;
+    }
+
+    #[allow(non_snake_case)]
+    fn BstoreOp(&mut self) {
+        self.b = self.Op() & 7;
+        self.op += 2;
+    }
+
+    #[allow(non_snake_case)]
+    fn Jnz(&mut self) {
+        if self.a != 0 {
+            self.op = self.program[self.op+1] as usize;
+        } else {
+            self.op += 2;
+        }
+    }
+
+    #[allow(non_snake_case)]
+    fn BxorC(&mut self) {
+        self.b ^= self.c;
+        self.op += 2;
+    }
+
+    #[allow(non_snake_case)]
+    fn Out(&mut self) -> bool {
+        // append ",{operand}" to the output string
+        self.output.push(self.Op() & 7);
+        self.op += 2;
+        if self.check && self.output[self.output.len()-1] != self.program[self.output.len()-1] {
+            return false;
+        }
+        true
+    }
+
+    #[allow(non_snake_case)]
+    fn BdivOp(&mut self) {
+        self.b = self.a >> self.Op();
+        self.op += 2;
+    }
+
+    #[allow(non_snake_case)]
+    fn CdivOp(&mut self) {
+        self.c = self.a >> self.Op();
+        self.op += 2;
+    }
+
+    #[allow(non_snake_case)]
+    fn Op(&self) -> u128{
+        let value = self.program[self.op+1];
+        if value <= 3 {
+            return value;
+        }
+        match value {
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            _ => panic!("Invalid opcode")
+        }
+    }
+
+    #[allow(non_snake_case)]
+    fn Run(&mut self) -> (bool, bool) {
+        let mut ok = true;
+        let mut ok_prefix = true;
+        while ok && self.op < self.program.len() {
+            match self.program[self.op] {
+                0 => self.AdivideOp(),
+                1 => self.BxorLit(),
+                2 => self.BstoreOp(),
+                3 => self.Jnz(),
+                4 => self.BxorC(),
+                5 => ok = self.Out(),
+                6 => self.BdivOp(),
+                7 => self.CdivOp(),
+                _ => panic!("Invalid opcode")
+            }
+        }
+        if self.check {
+            // check that the output is the same as the program
+            if self.output.len() != self.program.len() {
+                ok = false;
+            }
+            for i in 0..self.output.len() {
+                if self.output[i] != self.program[i] {
+                    ok_prefix = i == self.output.len()-1 && i > 0;
+                    ok = false
+                }
+            }
+        }
+        (ok, ok_prefix)
+    }
+
+}
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(machine: &Machine) -> String {
+    let mut machine = machine.clone();
+    machine.Run();
+    machine.output.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(",")
+}
+
+pub fn experiment(machine: &Machine) -> String {
+    let mut machine = machine.clone();
+    machine.Run();
+    let machine_output = machine.output.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(",");
+    machine_output
+}
+
+#[allow(unused_variables)]
+pub fn part2(machine: &Machine) -> String {
+    let (_,a) = test_target(machine, machine.program.len()-1, 0);
+    a.to_string()
+}
+
+fn test_target(machine: &Machine, target_index: usize, prefix: u128) -> (bool, u128) {
+    for suffix in 0..8 {
+        let mut test_machine = machine.clone();
+        test_machine.a = (prefix << 3) | suffix;
+        println!("Testing: {:b} = {}", test_machine.a, test_machine.a);
+        test_machine.Run();
+        let mut ok = true;
+        let mut j = test_machine.output.len()-1;
+        let mut i = machine.program.len()-1;
+        while ok && i >= target_index {
+            if test_machine.output[j] != machine.program[i] {
+                ok = false;
+                break;
+            }
+            if j > 0 && i > target_index {
+                j -= 1;
+                i -= 1;
+            } else {
+                if i != target_index {
+                    ok = false;
+                    break;
+                }
+                break;
+            }
+        }
+        if ok {
+            if target_index == 0 && test_machine.output.len() == machine.program.len() {
+                return (ok, (prefix << 3) | suffix);
+            } else {
+                if target_index != 0 {
+                    let (ok, a) = test_target(machine, target_index-1, (prefix << 3) | suffix);
+                    if ok {
+                        return (ok, a);
+                    }
+                }
+            }
+        }
+    }
+    (false, 0)
+}
+
+pub struct Day17;
+
+impl advent::Solution for Day17 {
+    type Input = Machine;
+
+    fn parse(contents: &str) -> Result<Machine, advent::Error> {
+        make_machine(contents)
+    }
+
+    fn part1(input: &Machine) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Machine) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+// use the advent package
+use advent::{parse, Grid, Point2D};
+
+// the size of the memory space, how many bytes have fallen for part 1, and where each byte falls, in order
+pub struct Memory {
+    width: i32,
+    height: i32,
+    fallen: usize,
+    bytes: Vec<(i32,i32)>,
+}
+
+// the size comes first as width,height, with how many bytes have fallen for part 1 after it if it
+// isn't the puzzle's 1024 (the example has 12), then after a blank line each byte's x,y
+fn make_memory(contents: &str) -> Result<Memory, advent::Error> {
+    let coordinates = |lines: &str| advent::try_parse_lines(lines, |line| parse::split_once_typed(line, ","));
+    let (header, bytes) = parse::sections(contents, parse::ints::<usize>, coordinates)?;
+    let (width, height, fallen) = match header[..] {
+        [width, height] => (width, height, 1024),
+        [width, height, fallen] => (width, height, fallen),
+        _ => return Err(advent::Error::expected("width,height or width,height,bytes").at_line(1)),
+    };
+    Ok(Memory { width: width as i32, height: height as i32, fallen, bytes })
+}
+
+pub fn part1(memory: &Memory) -> String {
+    let grid = make_grid(memory.width, memory.height, &get_coordinates(&memory.bytes, memory.fallen));
+    match steps_to_exit(&grid) {
+        Some(steps) => steps.to_string(),
+        None => "No path found".to_string(),
+    }
+}
+
+// the memory space, with a # where each byte has fallen
+fn make_grid(width: i32, height: i32, bytes: &HashSet<(i32,i32)>) -> Grid<char> {
+    let mut grid = Grid::new(width, height, '.');
+    for &(x, y) in bytes {
+        grid.set(Point2D::new(x, y), '#');
+    }
+    grid
+}
+
+// the fewest steps from the top left corner to the bottom right one, if there is a way
+fn steps_to_exit(grid: &Grid<char>) -> Option<usize> {
+    let exit = Point2D::new(grid.width()-1, grid.height()-1);
+    grid.bfs(Point2D::new(0, 0), |_, &c| c != '#').get(&exit).copied()
+}
+
+// where the first count bytes have fallen
+fn get_coordinates(bytes: &[(i32,i32)], count: usize) -> HashSet<(i32,i32)> {
+    bytes.iter().take(count).copied().collect()
+}
+
+pub fn part2(memory: &Memory) -> String {
+    let mut min = 0;
+    let mut max = memory.bytes.len()-1;
+    let mut count = (max+min)/2;
+    while max -  min > 1 {
+        let grid = make_grid(memory.width, memory.height, &get_coordinates(&memory.bytes, count+1));
+        if steps_to_exit(&grid).is_none() {
+            max = count;
+        } else {
+            min = count;
+        }
+        count = (min + max) / 2;
+    }
+    let (x, y) = memory.bytes[max];
+    format!("{},{}", x, y)
+}
+
+pub struct Day18;
+
+impl advent::Solution for Day18 {
+    type Input = Memory;
+
+    fn parse(contents: &str) -> Result<Memory, advent::Error> {
+        make_memory(contents)
+    }
+
+    fn part1(input: &Memory) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Memory) -> String {
+        part2(input)
+    }
+}
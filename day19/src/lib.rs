@@ -0,0 +1,99 @@
+// use the advent package
+use advent::{parse, Memo};
+
+// the towels there are, and the designs to make from them
+type Linens = (Vec<String>, Vec<String>);
+
+pub fn part1((towels, designs): &Linens) -> String {
+    // the towel pattern is the same for every design, so build it once
+    let towel_regex = towel_regex(towels);
+    let mut count = 0;
+    for design in designs {
+        if towel_regex.is_match(design) {
+            count += 1;
+        }
+    }
+    count.to_string()
+}
+
+// matches a design made of any number of towels, one after another
+fn towel_regex(towels: &[String]) -> regex::Regex {
+    let towel_regex = "^(".to_string() + &towels.join("|") + ")+$";
+    regex::Regex::new(&towel_regex).unwrap()
+}
+
+// the towels come first, on one line separated by commas, then the designs, one per line.
+// Both are made of stripe colors, and a towel with no stripes would match any design forever.
+fn parse_input(contents: &str) -> Result<Linens, advent::Error> {
+    let towels = |line: &str| {
+        let mut column = 1;
+        line.split(", ")
+            .map(|towel| {
+                // columns of problems within a towel count from the start of the line
+                let parsed = parse_stripes(towel).map_err(|mut e| {
+                    e.column = e.column.map(|c| c + column - 1);
+                    e
+                });
+                column += towel.len() + 2;
+                parsed
+            })
+            .collect()
+    };
+    parse::sections(contents, towels, |designs| advent::try_parse_lines(designs, parse_stripes))
+}
+
+fn parse_stripes(stripes: &str) -> Result<String, advent::Error> {
+    let bad = if stripes.is_empty() { Some(0) } else { stripes.find(|c: char| !c.is_ascii_lowercase()) };
+    match bad {
+        Some(i) => Err(advent::Error::expected("stripe colors like wubrg").at_column(i + 1)),
+        None => Ok(stripes.to_string()),
+    }
+}
+
+pub fn part2((towels, designs): &Linens) -> String {
+    let mut count = 0_u64;
+    let towel_array: Vec<&str> = towels.iter().map(String::as_str).collect();
+    let towel_slice = &towel_array[..];
+    let mut solutions = Memo::new(|memo, design: &&str| possible_solutions(towel_slice, design, memo));
+    let towel_regex = towel_regex(towels);
+    for design in designs {
+        if towel_regex.is_match(design) {
+            count += solutions.get(design.as_str());
+        }
+        // println!("Design: {} Count: {}", design, count);
+    }
+    count.to_string()
+}
+
+// the number of ways to make the design from the towels, remembering the answer for each part of a design
+fn possible_solutions<'a>(towels: &[&str], design: &'a str, memo: &mut Memo<'_, &'a str, u64>) -> u64 {
+    let mut solutions = 0;
+    for towel in towels {
+        if let Some(remaining) = design.strip_prefix(towel) {
+            if remaining.is_empty() {
+                solutions += 1;
+            } else {
+                solutions += memo.get(remaining);
+            }
+        }
+    }
+    solutions
+}
+
+pub struct Day19;
+
+impl advent::Solution for Day19 {
+    type Input = Linens;
+
+    fn parse(contents: &str) -> Result<Linens, advent::Error> {
+        parse_input(contents)
+    }
+
+    fn part1(input: &Linens) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Linens) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+// use the advent package
+use advent::{parse, Grid, Point2D};
+
+type Point = Point2D<i32>;
+
+// the racetrack, with where the race starts and ends, and how many steps a cheat has to save
+// to count in each part
+#[derive(Clone)]
+pub struct Map {
+    map: Grid<char>,
+    start: Point,
+    end: Point,
+    min_saving: [i32; 2],
+}
+
+// the cheapest number of steps from p to every point on the track, without cheating
+fn track_distances(map: &Map, p: Point) -> HashMap<Point, i32> {
+    advent::dijkstra_all(p, |&p| {
+        map.map.neighbors4(p).filter(|n| map.map[*n] != '#').map(|n| (n, 1)).collect::<Vec<_>>()
+    }).distance
+}
+
+// the puzzle counts cheats that save at least 100 steps in both parts, and the example input
+// gives its own smaller savings for part 1 and part 2 in a section before the map, like 1,50
+fn make_map(contents: &str) -> Result<Map, advent::Error> {
+    let (min_saving, map) = parse::with_settings(contents, [100, 100], parse::ints_array::<i32, 2>, Grid::parse_chars)?;
+    let start = map.find(&'S').ok_or_else(|| advent::Error::expected("a start S on the racetrack"))?;
+    let end = map.find(&'E').ok_or_else(|| advent::Error::expected("an end E on the racetrack"))?;
+    Ok(Map { map, start, end, min_saving })
+}
+
+// The number of cheats, of up to max_cheat steps through walls, that save at least min_saving steps.
+// A cheat from a to b makes the race the steps from the start to a, the cheat, then the steps from b to the end,
+// which works wherever the track branches.
+fn count_cheats(map: &Map, max_cheat: i32, min_saving: i32) -> Option<usize> {
+    let from_start = track_distances(map, map.start);
+    let from_end = track_distances(map, map.end);
+    let best = *from_start.get(&map.end)?;
+    let mut count = 0;
+    for (&a, &to_a) in &from_start {
+        for dy in -max_cheat..=max_cheat {
+            let reach = max_cheat - dy.abs();
+            for dx in -reach..=reach {
+                let Some(&from_b) = from_end.get(&(a + Point::new(dx, dy))) else {
+                    continue;
+                };
+                if best - (to_a + dx.abs() + dy.abs() + from_b) >= min_saving {
+                    count += 1;
+                }
+            }
+        }
+    }
+    Some(count)
+}
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(map: &Map) -> String {
+    // cheats can last 2 steps
+    match count_cheats(map, 2, map.min_saving[0]) {
+        Some(count) => count.to_string(),
+        None => "No path found".to_string(),
+    }
+}
+
+#[allow(unused_variables)]
+pub fn part2(map: &Map) -> String {
+    // cheats can last 20 steps
+    match count_cheats(map, 20, map.min_saving[1]) {
+        Some(count) => count.to_string(),
+        None => "No path found".to_string(),
+    }
+}
+
+pub struct Day20;
+
+impl advent::Solution for Day20 {
+    type Input = Map;
+
+    fn parse(contents: &str) -> Result<Map, advent::Error> {
+        make_map(contents)
+    }
+
+    fn part1(input: &Map) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Map) -> String {
+        part2(input)
+    }
+}
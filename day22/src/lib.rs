@@ -0,0 +1,150 @@
+use std::collections::{HashMap, HashSet};
+
+// use the advent package
+use advent::parse;
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(secrets: &[u128]) -> String {
+    let mut secrets2k = Vec::new();
+    let mut sum = 0;
+    for secret in secrets.iter() {
+        let x = *secret;
+        let mut y = x;
+        for _ in 0..2000 {
+            y = next(y);
+        }
+        secrets2k.push(y);
+        sum += y;
+        println!("{}: {}", x, y);
+    }
+    sum.to_string()
+}
+
+fn next(secret: u128) -> u128 {
+    step3(step2(step1(secret)))
+}
+
+fn step1(secret: u128) -> u128 {
+    prune(mix(secret, secret << 6))
+}
+
+fn step2(secret: u128) -> u128 {
+    prune(mix(secret, secret >> 5))
+}
+
+fn step3(secret: u128) -> u128 {
+    prune(mix(secret, secret*2048))
+}
+
+fn mix(secret: u128, x: u128) -> u128 {
+    x ^ secret
+}
+
+fn prune(x: u128) -> u128 {
+    x & 0xffffff
+}
+
+#[allow(unused_variables)]
+pub fn part2(secrets: &[u128]) -> String {
+    let mut secrets2klist: Vec<Vec<i32>> = Vec::new();
+    for secret in secrets.iter() {
+        let mut secrets2k = Vec::new();
+        let mut y = *secret;
+        for _ in 0..2000 {
+            y = next(y);
+            secrets2k.push((y%10) as i32);
+        }
+        secrets2klist.push(secrets2k);
+    }
+    let diff_codes_list = get_diff_codes(&secrets2klist);
+    let (highest_value_diff_code, highest_value) = get_highest_value_diff_code(&diff_codes_list);
+    println!("Highest Value Diff Code: {:?} {}", highest_value_diff_code, highest_value);
+    highest_value.to_string()
+}
+
+// create a type that is an array of four i32 values
+type DiffCode = [i32; 4];
+// struct DiffCodeEntry {
+//     dc: DiffCode,
+//     price: i32,
+// }
+
+// a monkey has a Vector of 2000 secrets.
+// the diference between four consectutive secrets is a DiffCode
+// the price is the entry in the last secret of that set of four for the first encounter of that sequence
+// get a HashMap of DiffCodeEntry from a vector of 2000 secrets
+fn get_diff_codes_for_monkey(secrets: &[i32]) -> HashMap<DiffCode, i32> {
+    let mut diff_codes: HashMap<DiffCode, i32> = HashMap::new();
+    for i in 0..1996 {
+        let dc: DiffCode = [secrets[i+1] - secrets[i], secrets[i+2] - secrets[i+1], secrets[i+3] - secrets[i+2], secrets[i+4] - secrets[i+3]];
+        diff_codes.entry(dc).or_insert(secrets[i+4]);
+    }
+    diff_codes
+}
+
+// get the diff_codes for all the monkeys
+fn get_diff_codes(secrets2klist: &[Vec<i32>]) -> Vec<HashMap<DiffCode, i32>> {
+    let mut diff_codes_list: Vec<HashMap<DiffCode, i32>> = Vec::new();
+    for secrets2k in secrets2klist.iter() {
+        diff_codes_list.push(get_diff_codes_for_monkey(secrets2k));
+    }
+    diff_codes_list
+}
+
+// the value of a diff code is the sum of the prices over each monkey's diff code price
+fn get_diff_code_value(diff_codes_list: &[HashMap<DiffCode, i32>], diff_code: DiffCode) -> i32 {
+    let mut sum = 0;
+    for diff_codes in diff_codes_list.iter() {
+        if let Some(price) = diff_codes.get(&diff_code) {
+            sum += price;
+        }
+    }
+    sum
+}
+
+// get a list of all the diff_codes over all the monkeys
+fn get_all_diff_codes(diff_codes_list: &[HashMap<DiffCode, i32>]) -> HashSet<DiffCode> {
+    let mut all_diff_codes: HashSet<DiffCode> = HashSet::new();
+    for diff_codes in diff_codes_list.iter() {
+        for (dc, _) in diff_codes.iter() {
+            all_diff_codes.insert(*dc);
+        }
+    }
+    all_diff_codes
+}
+
+// get the diff code with the highest value
+fn get_highest_value_diff_code(diff_codes_list: &[HashMap<DiffCode, i32>]) -> (DiffCode, i32) {
+    let all_diff_codes = get_all_diff_codes(diff_codes_list);
+    let mut highest_value = 0;
+    let mut highest_value_diff_code = [0, 0, 0, 0];
+    for diff_code in all_diff_codes.iter() {
+        let value = get_diff_code_value(diff_codes_list, *diff_code);
+        if value > highest_value {
+            highest_value = value;
+            highest_value_diff_code = *diff_code;
+        }
+    }
+    (highest_value_diff_code, highest_value)
+}
+
+
+pub struct Day22;
+
+impl advent::Solution for Day22 {
+    // each buyer's initial secret number, one per line
+    type Input = Vec<u128>;
+
+    fn parse(contents: &str) -> Result<Vec<u128>, advent::Error> {
+        parse::lines(contents)
+    }
+
+    fn part1(input: &Vec<u128>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<u128>) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,51 @@
+// use the advent package
+use advent::{parse, Graph};
+
+// the links between pairs of computers
+type Links = Vec<(String, String)>;
+
+pub fn part1(links: &Links) -> String {
+    let network = make_network(links);
+    count_t_triangles(&network).to_string()
+}
+
+fn make_network(links: &Links) -> Graph<&str> {
+    Graph::undirected_from(links.iter().map(|(a, b)| (a.as_str(), b.as_str())))
+}
+
+// each line a-b is a link between computers a and b
+fn parse_input(contents: &str) -> Result<Links, advent::Error> {
+    advent::try_parse_lines(contents, |line| parse::split_once_typed(line, "-"))
+}
+
+// the sets of three computers all linked to each other, with at least one whose name starts with t
+fn count_t_triangles(network: &Graph<&str>) -> usize {
+    network.cliques(3).iter().filter(|triangle| triangle.iter().any(|name| name.starts_with("t"))).count()
+}
+
+pub fn part2(links: &Links) -> String {
+    let network = make_network(links);
+    // the LAN party is the biggest set of computers all linked to each other, and the password
+    // is their names in order, separated by commas
+    let mut lan_party = network.maximum_clique();
+    lan_party.sort();
+    lan_party.join(",")
+}
+
+pub struct Day23;
+
+impl advent::Solution for Day23 {
+    type Input = Links;
+
+    fn parse(contents: &str) -> Result<Links, advent::Error> {
+        parse_input(contents)
+    }
+
+    fn part1(input: &Links) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Links) -> String {
+        part2(input)
+    }
+}
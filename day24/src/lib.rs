@@ -0,0 +1,222 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+// use the advent package
+use advent::parse;
+
+// the wires with their starting values, and the gates that set the rest
+pub struct Device {
+    wires: HashMap<String, bool>,
+    gates: Vec<Gate>,
+}
+
+// a gate's two input wires, its operation, and its output wire
+type Gate = (String, String, Op, String);
+
+// turn off warning for unused variables
+#[allow(unused_variables)]
+pub fn part1(device: &Device) -> String {
+    let mut wires = device.wires.clone();
+    let gates = &device.gates;
+    let wire_list = wire_list(gates);
+    while wires.len() < wire_list.len() {
+        for gate in gates {
+            apply(&mut wires, &gate.0, &gate.1, gate.2, &gate.3);
+        }
+    }
+    // get a Vector of the wire names that start with "z"
+    let x_value = get_value(&wires, "x".to_string());
+    let y_value = get_value(&wires, "y".to_string());
+    let answer = get_value(&wires, "z".to_string());
+    println!("Answer: {:b} + {:b} =\n{:b}\nExpected\n{:b}", x_value, y_value, answer, x_value+y_value);
+    answer.to_string()
+}
+
+fn get_value(wires: &HashMap<String, bool>, wire_prefix: String) -> u128 {
+    let mut prefix_wires = Vec::new();
+    for key in wires.keys() {
+        if key.starts_with(wire_prefix.as_str()) {
+            prefix_wires.push(key);
+        }
+    }
+    // sort the Vector of wire names in reverse order
+    let mut answer: u128 = 0;
+    prefix_wires.sort_by(|a, b| b.cmp(a));
+    for wire in prefix_wires {
+        answer = answer << 1 | *wires.get(wire).unwrap() as u128;
+    }
+    answer
+}
+
+// named the way the puzzle writes the gates
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum Op {
+    AND,
+    OR,
+    XOR,
+}
+
+impl FromStr for Op {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Op, String> {
+        match s {
+            "AND" => Ok(Op::AND),
+            "OR" => Ok(Op::OR),
+            "XOR" => Ok(Op::XOR),
+            _ => Err("expected AND, OR or XOR".to_string()),
+        }
+    }
+}
+
+fn apply(wires: &mut HashMap<String, bool>, in1: &str, in2: &str, op: Op, output: &str) -> Option<bool> {
+    if wires.contains_key(in1) && wires.contains_key(in2) {
+        let a = *wires.get(in1).unwrap();
+        let b = *wires.get(in2).unwrap();
+        let o = match op {
+            Op::AND => Some(a && b),
+            Op::OR => Some(a || b),
+            Op::XOR => Some(a ^ b),
+            // _ => None,
+        };
+        if let Some(value) = o {
+            wires.insert(output.to_string(), value);
+        }
+        return o;
+    }
+    None
+}
+
+// the initial wire values like x00: 1, then after a blank line the gates like x00 AND y00 -> z00
+fn make_device(contents: &str) -> Result<Device, advent::Error> {
+    let (wires, gates) = parse::sections(contents, initialize_wires, |section| advent::try_parse_lines(section, parse_gate))?;
+    Ok(Device { wires, gates })
+}
+
+fn parse_gate(line: &str) -> Result<Gate, advent::Error> {
+    let (in1, op, in2, output) = parse::scan("{} {} {} -> {}", line)?;
+    Ok((in1, in2, op, output))
+}
+
+// every wire a gate reads or sets
+fn wire_list(gates: &[Gate]) -> HashSet<&str> {
+    gates.iter().flat_map(|gate| [gate.0.as_str(), gate.1.as_str(), gate.3.as_str()]).collect()
+}
+
+fn initialize_wires(section: &str) -> Result<HashMap<String, bool>, advent::Error> {
+    let settings: Vec<(String, u8)> = advent::try_parse_lines(section, |line| parse::split_once_typed(line, ": "))?;
+    Ok(settings.into_iter().map(|(wire, value)| (wire, value == 1)).collect())
+}
+
+#[allow(unused_variables)]
+pub fn part2(device: &Device) -> String {
+    let mut wires = device.wires.clone();
+    let gates = &device.gates;
+    let wire_list = wire_list(gates);
+    while wires.len() < wire_list.len() {
+        for gate in gates {
+            apply(&mut wires, &gate.0, &gate.1, gate.2, &gate.3);
+        }
+    }
+    // get a Vector of the wire names that start with "z"
+    let x_value = get_value(&wires, "x".to_string());
+    let y_value = get_value(&wires, "y".to_string());
+    let z_value = get_value(&wires, "z".to_string());
+    println!("Answer: {:b} + {:b} =\n{:b}\nExpected\n{:b}", x_value, y_value, z_value, x_value+y_value);
+    // find which z bits don't match the expected value
+    let answer = x_value + y_value;
+    let mut mask: u128 = 1;
+    let mut bit = 0;
+    let mut wrong_bits = Vec::new();
+    while mask <= answer {
+        if (answer & mask) != (z_value & mask) {
+            // create the name of the z gate that sets the wrong bit. The name is "z" followed by the two digit bit number
+            let gate_name = format!("z{:02}", bit);
+            wrong_bits.push(gate_name);
+        }
+        mask <<= 1;
+        bit += 1;
+    }
+    // get the list of gates that eventually set the wrong bits
+    let mut wrong_gates = HashSet::new();
+    // create a stack of the z gates, so that we can trace back to find all wires involved in gates that set the wrong bits
+    let mut stack = wrong_bits.clone();
+    while let Some(gate_name) = stack.pop() {
+        for gate in gates {
+            if gate.3 == gate_name {
+                wrong_gates.insert(gate);
+                stack.push(gate.0.to_string());
+                stack.push(gate.1.to_string());
+            }
+        }
+    }
+    // print the wrong gates
+    println!("Wrong gates: {}", wrong_gates.len());
+    // // get the list of probably correct gates
+    // let mut right_bits = Vec::new();
+    // while mask <= answer {
+    //     if (answer & mask) == (z_value & mask) {
+    //         // create the name of the z gate that sets the wrong bit. The name is "z" followed by the two digit bit number
+    //         let gate_name = format!("z{:02}", bit);
+    //         right_bits.push(gate_name);
+    //     }
+    //     mask = mask << 1;
+    //     bit += 1;
+    // }
+    // // get the list of gates that eventually set the wrong bits
+    // let mut right_gates = HashSet::new();
+    // // create a stack of the z gates, so that we can trace back to find all wires involved in gates that set the wrong bits
+    // let mut stack = right_bits.clone();
+    // while stack.len() > 0 {
+    //     let gate_name = stack.pop().unwrap();
+    //     for gate in &gates {
+    //         if gate.3 == gate_name {
+    //             right_gates.insert(gate);
+    //             stack.push(gate.0.to_string());
+    //             stack.push(gate.1.to_string());
+    //         }
+    //     }
+    // }
+    // // get the list of wrong gates that are not in the list of right gates
+    // let mut wrong_gates2 = HashSet::new();
+    // for gate in &wrong_gates {
+    //     let mut found = false;
+    //     for right_gate in &right_gates {
+    //         if gate.3 == right_gate.3 {
+    //             found = true;
+    //             break;
+    //         }
+    //     }
+    //     if !found {
+    //         wrong_gates2.insert(gate);
+    //     }
+    // }
+    // // print the count of wrong2 gates
+    // println!("Wrong2 gates: {}", wrong_gates2.len());
+    // try every combination of four pairs of wrong__gates2
+    // combos8 is an index into wrong_gates2
+    // let initial_settings = sections[0].lines().collect::<Vec<&str>>();
+    // let mut wires = initialize_wires(initial_settings);
+
+    2.to_string()
+}
+
+
+pub struct Day24;
+
+impl advent::Solution for Day24 {
+    type Input = Device;
+
+    fn parse(contents: &str) -> Result<Device, advent::Error> {
+        make_device(contents)
+    }
+
+    fn part1(input: &Device) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Device) -> String {
+        part2(input)
+    }
+}
@@ -0,0 +1,70 @@
+use std::path::PathBuf;
+
+use advent::Day;
+
+// Every day's solution, in order
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::new::<day00::Day00>(0, "day00"),
+        Day::new::<day01::Day01>(1, "day01"),
+        Day::new::<day02::Day02>(2, "day02"),
+        Day::new::<day03::Day03>(3, "day03"),
+        Day::new::<day04::Day04>(4, "day04"),
+        Day::new::<day05::Day05>(5, "day05"),
+        Day::new::<day06::Day06>(6, "day06"),
+        Day::new::<day07::Day07>(7, "day07"),
+        Day::new::<day08::Day08>(8, "day08"),
+        Day::new::<day09::Day09>(9, "day09"),
+        Day::new::<day10::Day10>(10, "day10"),
+        Day::new::<day11::Day11>(11, "day11"),
+        Day::new::<day12::Day12>(12, "day12"),
+        Day::new::<day13::Day13>(13, "day13"),
+        Day::new::<day14::Day14>(14, "day14"),
+        Day::new::<day15::Day15>(15, "day15"),
+        Day::new::<day16::Day16>(16, "day16"),
+        Day::new::<day17::Day17>(17, "day17"),
+        Day::new::<day18::Day18>(18, "day18"),
+        Day::new::<day19::Day19>(19, "day19"),
+        Day::new::<day20::Day20>(20, "day20"),
+        Day::new::<day21::Day21>(21, "day21"),
+        Day::new::<day22::Day22>(22, "day22"),
+        Day::new::<day23::Day23>(23, "day23"),
+        Day::new::<day24::Day24>(24, "day24"),
+    ]
+}
+
+pub fn find_day(number: u32) -> Option<Day> {
+    days().into_iter().find(|day| day.number == number)
+}
+
+// The directory a day's crate lives in, next to this one in the repository
+pub fn day_dir(day: &Day) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(day.name)
+}
+
+// The puzzle input is kept in each day's files directory
+pub fn default_input(day: &Day) -> PathBuf {
+    day_dir(day).join("files").join("input")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_has_every_day() {
+        let days = days();
+        assert_eq!(days.len(), 25);
+        for (i, day) in days.iter().enumerate() {
+            assert_eq!(day.number, i as u32);
+            assert_eq!(day.name, format!("day{:02}", i));
+            assert!(day_dir(day).join("Cargo.toml").exists());
+        }
+    }
+
+    #[test]
+    fn test_find_day() {
+        assert_eq!(find_day(16).unwrap().name, "day16");
+        assert!(find_day(25).is_none());
+    }
+}
@@ -0,0 +1,159 @@
+use std::path::{Path, PathBuf};
+
+use advent::bench::DayReport;
+use advent::Day;
+
+const USAGE: &str = "Usage: advent run <day|all> [--part <1|2>] [--visualize] [--render <directory>] [input file]
+       advent bench <day|all> [--runs <n>] [--json <file>] [input file]";
+
+// Counting allocations lets bench report how much memory each part needs
+#[global_allocator]
+static ALLOCATOR: advent::bench::CountingAllocator = advent::bench::CountingAllocator;
+
+fn main() {
+    let args = advent::get_commandline_arguments();
+    if args.len() < 2 || (args[0] != "run" && args[0] != "bench") {
+        eprintln!("{}", USAGE);
+        std::process::exit(1);
+    }
+    let bench = args[0] == "bench";
+    let only_for = |command: &str, flag: &str| {
+        if args[0] != command {
+            usage_error(&format!("{} is only for {}", flag, command));
+        }
+    };
+    let mut parts = vec![1, 2];
+    let mut runs = 10;
+    let mut json = None;
+    let mut input = None;
+    let mut rest = args[2..].iter();
+    while let Some(arg) = rest.next() {
+        if arg == "--part" {
+            only_for("run", arg);
+            match rest.next().map(|p| p.as_str()) {
+                Some("1") => parts = vec![1],
+                Some("2") => parts = vec![2],
+                _ => usage_error("--part must be 1 or 2"),
+            }
+        } else if arg == "--visualize" {
+            only_for("run", arg);
+            advent::set_visualize(true);
+        } else if arg == "--render" {
+            only_for("run", arg);
+            match rest.next() {
+                Some(directory) => advent::set_render(Some(PathBuf::from(directory))),
+                None => usage_error("--render needs a directory"),
+            }
+        } else if arg == "--runs" {
+            only_for("bench", arg);
+            match rest.next().and_then(|n| n.parse::<usize>().ok()).filter(|&n| n > 0) {
+                Some(n) => runs = n,
+                None => usage_error("--runs must be a number of at least 1"),
+            }
+        } else if arg == "--json" {
+            only_for("bench", arg);
+            match rest.next() {
+                Some(file) => json = Some(PathBuf::from(file)),
+                None => usage_error("--json needs a file"),
+            }
+        } else if input.is_none() {
+            input = Some(PathBuf::from(arg));
+        } else {
+            usage_error(&format!("unexpected argument {}", arg));
+        }
+    }
+
+    let mut days = Vec::new();
+    if args[1] == "all" {
+        if input.is_some() {
+            usage_error("an input file can only be given for a single day");
+        }
+        for day in runner::days() {
+            let input = runner::default_input(&day);
+            // not every day has its puzzle input checked out, so skip the ones that don't
+            if !input.exists() {
+                println!("Day {:02}: no input at {}\n", day.number, input.display());
+                continue;
+            }
+            days.push((day, input));
+        }
+    } else {
+        let day = match args[1].parse::<u32>().ok().and_then(runner::find_day) {
+            Some(day) => day,
+            None => usage_error(&format!("there is no day {}", args[1])),
+        };
+        let input = input.unwrap_or_else(|| runner::default_input(&day));
+        days.push((day, input));
+    }
+
+    // keep going after a bad input so the other days still run
+    let mut failed = false;
+    let mut reports = Vec::new();
+    for (day, input) in &days {
+        if bench {
+            match bench_day(day, input, runs) {
+                Some(report) => reports.push(report),
+                None => failed = true,
+            }
+        } else {
+            failed |= !run_day(day, input, &parts);
+        }
+    }
+    if let Some(json) = json {
+        if let Err(e) = std::fs::write(&json, advent::bench::to_json(runs, &reports)) {
+            eprintln!("can't write {}: {}", json.display(), e);
+            failed = true;
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+// Returns false if the input couldn't be read or parsed, after reporting why
+fn run_day(day: &Day, input: &Path, parts: &[u32]) -> bool {
+    println!("Day {:02}:", day.number);
+    let input = input.to_string_lossy();
+    let results = advent::try_read_file_to_string(&input)
+        .and_then(|contents| (day.solve)(&contents, parts))
+        .map_err(|e| e.in_file(input.as_ref()).in_day(day.name));
+    let ok = match results {
+        Ok(results) => {
+            results.iter().for_each(advent::print_part_result);
+            true
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            false
+        }
+    };
+    println!();
+    ok
+}
+
+// Returns None if the input couldn't be read or parsed, after reporting why
+fn bench_day(day: &Day, input: &Path, runs: usize) -> Option<DayReport> {
+    println!("Day {:02} ({} runs):", day.number, runs);
+    let input = input.to_string_lossy();
+    let measurements = advent::try_read_file_to_string(&input)
+        .and_then(|contents| (day.bench)(&contents, runs))
+        .map_err(|e| e.in_file(input.as_ref()).in_day(day.name));
+    let report = match measurements {
+        Ok(measurements) => {
+            let report = DayReport { number: day.number, name: day.name.to_string(), measurements };
+            print!("{}", advent::bench::format_report(&report));
+            Some(report)
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            None
+        }
+    };
+    println!();
+    report
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n{}", message, USAGE);
+    std::process::exit(1);
+}
@@ -0,0 +1,17 @@
+use libtest_mimic::{Arguments, Trial};
+
+// One test case for every example input and answer pair found in each day's files directory.
+// Answers marked with an _ignore file are known failures, and only run with --ignored.
+fn main() {
+    let args = Arguments::from_args();
+    let mut trials = Vec::new();
+    for day in runner::days() {
+        for fixture in advent::fixtures::discover(&runner::day_dir(&day).join("files")) {
+            let name = format!("{}::{}_part{}", day.name, fixture.name, fixture.part);
+            let solve = day.solve;
+            let ignored = fixture.ignored.is_some();
+            trials.push(Trial::test(name, move || fixture.check(solve).map_err(Into::into)).with_ignored_flag(ignored));
+        }
+    }
+    libtest_mimic::run(&args, trials).exit();
+}
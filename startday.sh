@@ -24,15 +24,15 @@ else
   echo "Directory day$1 already exists"
 fi
 
-# use cargo to initialize the directory
+# use cargo to initialize the directory (cargo adds it to the workspace members)
 cd "day$1"
-cargo init --bin
+cargo init --lib
 
 # check if "advent" is a dependency in the "Cargo.toml" file
 if grep -q advent Cargo.toml; then
   echo "advent dependency already exists in Cargo.toml"
 else
-  sed -i '' 's/\[dependencies\]/[dependencies]\nadvent = { path = "..\/advent" }/' Cargo.toml
+  sed -i '' 's/\[dependencies\]/[dependencies]\nadvent = { workspace = true }/' Cargo.toml
 fi
 # add "advent" to the [dependencies] section in the "Cargo.toml" file
 
@@ -42,8 +42,9 @@ if ! grep -q advent Cargo.toml; then
   exit 1
 fi
 
-# copy the template files from "day00/src" into the directory
+# copy the template files from "day00/src" into the directory, and rename the solution for this day
 cp ../day00/src/* src/
+sed -i '' "s/day00/day$1/g; s/Day00/Day$1/g" src/*.rs
 cp ../day00/README.md README.md
 
 # If the files/test directory does not exist or no files exist in that directory, create an empty test input file and default test answers
@@ -58,3 +59,5 @@ fi
 # check that the project builds, and run the tests
 cargo build
 cargo test
+
+echo "Add day$1 to runner/Cargo.toml and the list in runner/src/lib.rs to run it with the advent runner"